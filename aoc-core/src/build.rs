@@ -0,0 +1,15 @@
+//! Helpers for the build scripts of the individual days.
+
+use std::path::Path;
+
+/// Sets the `has_input` cfg when the day ships a `src/input.txt`.
+///
+/// Puzzle inputs are personal and not part of the repository, so a day can only embed its input when
+/// the file is present. Without it the binary still builds and expects the input at runtime.
+pub fn detect_input() {
+    println!("cargo::rustc-check-cfg=cfg(has_input)");
+    println!("cargo::rerun-if-changed=src");
+    if Path::new("src/input.txt").exists() {
+        println!("cargo::rustc-cfg=has_input");
+    }
+}
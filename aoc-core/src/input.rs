@@ -0,0 +1,25 @@
+use std::borrow::Cow;
+use std::io::Read;
+
+use anyhow::{Context, Result};
+
+/// Loads the puzzle input from `path`, or from stdin when `path` is `-`.
+///
+/// Without a path the `embedded` input is used, when there is one.
+pub fn load(path: Option<&str>, embedded: Option<&'static str>) -> Result<Cow<'static, str>> {
+    match path {
+        Some("-") => {
+            let mut data = String::new();
+            std::io::stdin()
+                .read_to_string(&mut data)
+                .context("Failed to read input from stdin")?;
+            Ok(Cow::Owned(data))
+        }
+        Some(path) => std::fs::read_to_string(path)
+            .map(Cow::Owned)
+            .with_context(|| format!("Failed to read input from {path}")),
+        None => embedded.map(Cow::Borrowed).context(
+            "No input.txt was embedded at build time, pass the input file as an argument or - for stdin",
+        ),
+    }
+}
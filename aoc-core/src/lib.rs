@@ -0,0 +1,160 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use took::Took;
+
+pub mod build;
+mod input;
+pub mod output;
+mod parse;
+
+pub use input::load;
+pub use output::Format;
+pub use parse::{finish, parse_grid, ParseError};
+
+/// A single day of the Advent of Code, split into parsing and the two parts.
+pub trait Solution {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+
+    /// The parsed puzzle input shared by both parts, possibly borrowing from the raw input.
+    type Input<'a>;
+    /// The answer to part one.
+    type PartOne: Display;
+    /// The answer to part two.
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne>;
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo>;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(anyhow::Error::msg(format!("There is no part {value}"))),
+        }
+    }
+}
+
+/// Answers and timings of a run of a [`Solution`]; parts that were not asked for are `None`.
+#[derive(Debug)]
+pub struct Report {
+    pub parse: Duration,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Report {
+    /// The answers that were computed, in part order.
+    pub fn answers(&self) -> impl Iterator<Item = (Part, &Answer)> {
+        [(Part::One, &self.part_one), (Part::Two, &self.part_two)]
+            .into_iter()
+            .filter_map(|(part, answer)| answer.as_ref().map(|answer| (part, answer)))
+    }
+}
+
+/// The answer to one part, together with the time it took to compute.
+#[derive(Debug)]
+pub struct Answer {
+    pub value: String,
+    pub took: Duration,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Time spent parsing: {}", Took::from_std(self.parse))?;
+        for (part, answer) in self.answers() {
+            let name = match part {
+                Part::One => "one",
+                Part::Two => "two",
+            };
+            writeln!(f, "Result part {name}: {}", answer.value)?;
+            writeln!(f, "Time spent: {}", Took::from_std(answer.took))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// [`solve`] for a specific [`Solution`], so different days can be kept side by side.
+pub type Solver = fn(&str, Option<Part>) -> Result<Report>;
+
+/// Parses `data` and solves the given `part`, or both parts when `None`, timing every step.
+pub fn solve<S: Solution>(data: &str, part: Option<Part>) -> Result<Report> {
+    let (parse, input) = took::took(|| S::parse(data));
+    let input = input?;
+
+    let part_one = match part {
+        Some(Part::Two) => None,
+        _ => Some(timed(|| S::part_one(&input))?),
+    };
+    let part_two = match part {
+        Some(Part::One) => None,
+        _ => Some(timed(|| S::part_two(&input))?),
+    };
+
+    Ok(Report {
+        parse: parse.into_std(),
+        part_one,
+        part_two,
+    })
+}
+
+/// Solves the input given on the command line with `S` and prints the report to stdout.
+///
+/// The arguments are the path of the input file, or `-` to read it from stdin, and optionally
+/// `--format human|json|csv`. Without a path the `embedded` input is used.
+pub fn run<S: Solution>(embedded: Option<&'static str>) -> Result<()> {
+    let mut path = None;
+    let mut format = Format::Human;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = args.next().context("--format needs a value")?.parse()?,
+            _ if path.is_none() => path = Some(arg),
+            _ => bail!("Unexpected argument {arg}"),
+        }
+    }
+
+    let data = load(path.as_deref(), embedded)?;
+    let report = solve::<S>(&data, None)?;
+    match format {
+        Format::Human => print!("{report}"),
+        _ => output::write(&mut std::io::stdout().lock(), format, &[(S::DAY, report)])?,
+    }
+
+    Ok(())
+}
+
+fn timed<T: Display>(f: impl FnOnce() -> Result<T>) -> Result<Answer> {
+    let (took, result) = took::took(f);
+
+    Ok(Answer {
+        value: result?.to_string(),
+        took: took.into_std(),
+    })
+}
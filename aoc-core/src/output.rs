@@ -0,0 +1,171 @@
+use std::io::Write;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+use took::Took;
+
+use crate::Report;
+
+/// How reports are written: a table for people, or one record per day and part for tooling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => bail!("Unknown output format {s}, expected human, json or csv"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    parse_ns: u128,
+    solve_ns: u128,
+}
+
+/// Writes the `reports`, each tagged with its day, to `out` in the given `format`.
+///
+/// `Json` writes one JSON object per line and `Csv` writes a header followed by one row per day and
+/// part. Durations in both are in nanoseconds.
+pub fn write(out: &mut impl Write, format: Format, reports: &[(u8, Report)]) -> Result<()> {
+    let records = reports.iter().flat_map(|(day, report)| {
+        report.answers().map(|(part, answer)| Record {
+            day: *day,
+            part: part.number(),
+            answer: &answer.value,
+            parse_ns: report.parse.as_nanos(),
+            solve_ns: answer.took.as_nanos(),
+        })
+    });
+
+    match format {
+        Format::Human => write_table(out, reports)?,
+        Format::Json => {
+            for record in records {
+                serde_json::to_writer(&mut *out, &record)?;
+                writeln!(out)?;
+            }
+        }
+        Format::Csv => {
+            writeln!(out, "day,part,answer,parse_ns,solve_ns")?;
+            for record in records {
+                writeln!(
+                    out,
+                    "{},{},{},{},{}",
+                    record.day,
+                    record.part,
+                    csv_field(record.answer),
+                    record.parse_ns,
+                    record.solve_ns
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_table(out: &mut impl Write, reports: &[(u8, Report)]) -> Result<()> {
+    let width = reports
+        .iter()
+        .flat_map(|(_, report)| report.answers())
+        .map(|(_, answer)| answer.value.len())
+        .max()
+        .unwrap_or_default()
+        .max("Answer".len());
+
+    writeln!(
+        out,
+        "Day  Part  {:<width$}  {:>10}  {:>10}",
+        "Answer", "Parse", "Solve"
+    )?;
+    for (day, report) in reports {
+        for (part, answer) in report.answers() {
+            writeln!(
+                out,
+                "{day:>3}  {:>4}  {:<width$}  {:>10}  {:>10}",
+                part.number(),
+                answer.value,
+                Took::from_std(report.parse),
+                Took::from_std(answer.took),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Answer;
+
+    fn reports() -> Vec<(u8, Report)> {
+        vec![(
+            6,
+            Report {
+                parse: Duration::from_nanos(1500),
+                part_one: Some(Answer {
+                    value: "288".to_string(),
+                    took: Duration::from_nanos(20),
+                }),
+                part_two: Some(Answer {
+                    value: "71,503".to_string(),
+                    took: Duration::from_nanos(3000),
+                }),
+            },
+        )]
+    }
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let mut out = vec![];
+        write(&mut out, Format::Json, &reports())?;
+
+        assert_eq!(
+            "{\"day\":6,\"part\":1,\"answer\":\"288\",\"parse_ns\":1500,\"solve_ns\":20}\n\
+             {\"day\":6,\"part\":2,\"answer\":\"71,503\",\"parse_ns\":1500,\"solve_ns\":3000}\n",
+            String::from_utf8(out)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv() -> Result<()> {
+        let mut out = vec![];
+        write(&mut out, Format::Csv, &reports())?;
+
+        assert_eq!(
+            "day,part,answer,parse_ns,solve_ns\n6,1,288,1500,20\n6,2,\"71,503\",1500,3000\n",
+            String::from_utf8(out)?
+        );
+
+        Ok(())
+    }
+}
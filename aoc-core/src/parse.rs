@@ -0,0 +1,227 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use grid::{Grid, GridError};
+use nom::{IResult, Offset};
+
+const SNIPPET_LENGTH: usize = 30;
+
+/// A malformed puzzle input, pointing at where in the input the problem is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error at the start of `remaining`, which has to be a slice of `input`.
+    pub fn at(input: &str, remaining: &str, message: impl Into<String>) -> Self {
+        let offset = input.offset(remaining);
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map_or(before, |newline| &before[newline + 1..])
+            .chars()
+            .count()
+            + 1;
+        let snippet = input[offset..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(SNIPPET_LENGTH)
+            .collect();
+
+        ParseError {
+            line,
+            column,
+            snippet,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error at `column` of `line` of `input`, both counting from 1, for problems that are found
+    /// by position rather than while parsing. Positions past the end of a line point at its end.
+    pub fn at_position(
+        input: &str,
+        line: usize,
+        column: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        let offset = input
+            .split_inclusive('\n')
+            .take(line.saturating_sub(1))
+            .map(str::len)
+            .sum::<usize>();
+        let rest = &input[offset..];
+        let length = rest.lines().next().unwrap_or_default().len();
+        let column = rest
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map_or(length, |(i, _)| i.min(length));
+
+        Self::at(input, &rest[column..], message)
+    }
+
+    /// Positions a [`GridError`] in the `input` the grid was parsed from.
+    pub fn from_grid(input: &str, error: GridError) -> Self {
+        match error {
+            GridError::InvalidCell {
+                line,
+                column,
+                value,
+            } => Self::at_position(input, line, column, format!("Invalid cell {value:?}")),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => Self::at_position(
+                input,
+                line,
+                1,
+                format!("Expected {expected} cells but found {found}"),
+            ),
+        }
+    }
+}
+
+/// Parses `input` into a grid with one cell per character, failing with the position of the first invalid cell
+/// or ragged line.
+pub fn parse_grid<T: TryFrom<char>>(input: &str) -> Result<Grid<T>, ParseError> {
+    input
+        .parse::<Grid<T>>()
+        .map_err(|error| ParseError::from_grid(input, error))
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}: {:?}",
+            self.message, self.line, self.column, self.snippet
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Turns the result of running a nom parser over the whole of `input` into the parsed value.
+///
+/// Fails with the position of the problem when the parser failed, or when anything but whitespace
+/// is left after it.
+pub fn finish<T>(input: &str, result: IResult<&str, T>) -> Result<T, ParseError> {
+    match result {
+        Ok((remaining, value)) => {
+            let remaining = remaining.trim_start();
+            if remaining.is_empty() {
+                Ok(value)
+            } else {
+                Err(ParseError::at(input, remaining, "Unexpected input"))
+            }
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::at(
+            input,
+            e.input,
+            format!("Expected {}", e.code.description()),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "Unexpected end of input",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{self, line_ending},
+        multi::separated_list1,
+        sequence::preceded,
+    };
+
+    use super::*;
+
+    fn parse(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list1(line_ending, preceded(tag("n "), complete::u32))(input)
+    }
+
+    #[test]
+    fn test_finish() {
+        let input = "n 1\nn 2\n";
+
+        assert_eq!(Ok(vec![1, 2]), finish(input, parse(input)));
+    }
+
+    #[test]
+    fn test_finish_error() {
+        let input = "n 1\nm 2\n";
+
+        assert_eq!(
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                snippet: "m 2".to_string(),
+                message: "Unexpected input".to_string(),
+            }),
+            finish(input, parse(input))
+        );
+
+        let input = "n x\n";
+
+        assert_eq!(
+            Err(ParseError {
+                line: 1,
+                column: 3,
+                snippet: "x".to_string(),
+                message: "Expected Digit".to_string(),
+            }),
+            finish(input, parse(input))
+        );
+    }
+
+    #[test]
+    fn test_at() {
+        let input = "first\nsecond line";
+
+        let error = ParseError::at(input, &input[13..], "Oops");
+
+        assert_eq!(2, error.line);
+        assert_eq!(8, error.column);
+        assert_eq!("line", error.snippet);
+        assert_eq!("Oops at line 2, column 8: \"line\"", error.to_string());
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let input = "ab\nc\n";
+
+        assert_eq!(
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                snippet: "c".to_string(),
+                message: "Expected 2 cells but found 1".to_string(),
+            }),
+            parse_grid::<char>(input)
+        );
+    }
+
+    #[test]
+    fn test_at_position() {
+        let input = "first\nsecond line";
+
+        assert_eq!(
+            ParseError::at(input, &input[13..], "Oops"),
+            ParseError::at_position(input, 2, 8, "Oops")
+        );
+        assert_eq!(
+            ParseError::at(input, &input[5..], "Oops"),
+            ParseError::at_position(input, 1, 20, "Oops")
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::hint::black_box;
+use std::path::Path;
+
+use aoc_core::{Part, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const FILES: [&str; 2] = ["test.txt", "input.txt"];
+
+/// Benchmarks parsing and both parts of `S` against each of the day's input files that is present.
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    bench_parts::<S>(c, &[Part::One, Part::Two]);
+}
+
+fn bench_parts<S: Solution>(c: &mut Criterion, parts: &[Part]) {
+    for file in FILES {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{}/src/{file}", S::DAY));
+        let Ok(data) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let input = match S::parse(&data) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping {}: {e:#}", path.display());
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("day{}/{file}", S::DAY));
+        group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&data))));
+        if parts.contains(&Part::One) {
+            group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&input))));
+        }
+        if parts.contains(&Part::Two) {
+            group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&input))));
+        }
+        group.finish();
+    }
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c);
+    bench_day::<day2::Day2>(c);
+    bench_day::<day3::Day3>(c);
+    bench_day::<day4::Day4>(c);
+    bench_day::<day5::Day5>(c);
+    bench_day::<day6::Day6>(c);
+    bench_day::<day7::Day7>(c);
+    bench_day::<day8::Day8>(c);
+    bench_day::<day9::Day9>(c);
+    // Part two of days 10, 12 and 13 is not solved yet.
+    bench_parts::<day10::Day10>(c, &[Part::One]);
+    bench_day::<day11::Day11>(c);
+    bench_parts::<day12::Day12>(c, &[Part::One]);
+    bench_parts::<day13::Day13>(c, &[Part::One]);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
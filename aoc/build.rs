@@ -0,0 +1,79 @@
+//! Generates a test for every entry in the workspace's `answers.toml`.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Manifest {
+    answer: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: toml::Value,
+    #[serde(default)]
+    slow: bool,
+    ignore: Option<String>,
+}
+
+fn main() {
+    let root = Path::new("..");
+    let manifest = root.join("answers.toml");
+    println!("cargo::rerun-if-changed={}", manifest.display());
+
+    let manifest = std::fs::read_to_string(&manifest).expect("answers.toml should be readable");
+    let manifest: Manifest = toml::from_str(&manifest).expect("answers.toml should be valid");
+
+    let mut tests = String::new();
+    let mut sources = BTreeSet::new();
+    for entry in &manifest.answer {
+        let part = match entry.part {
+            1 => "one",
+            2 => "two",
+            part => panic!("Day {} has no part {part}", entry.day),
+        };
+        let answer = match &entry.answer {
+            toml::Value::Integer(answer) => answer.to_string(),
+            toml::Value::String(answer) => answer.clone(),
+            answer => panic!("{answer} is not a valid answer"),
+        };
+        let source = root.join(format!("day{}/src", entry.day));
+        let path = source.join(&entry.input);
+        let stem = Path::new(&entry.input)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .expect("input should be a file name")
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+        writeln!(tests, "#[test]").unwrap();
+        if !path.exists() {
+            writeln!(tests, "#[ignore = \"{} is missing\"]", path.display()).unwrap();
+        } else if let Some(reason) = &entry.ignore {
+            writeln!(tests, "#[ignore = {reason:?}]").unwrap();
+        } else if entry.slow {
+            writeln!(tests, "#[ignore = \"slow\"]").unwrap();
+        }
+        writeln!(
+            tests,
+            "fn day{}_part_{part}_{stem}() -> anyhow::Result<()> {{\n    check({}, {}, {:?}, {answer:?})\n}}\n",
+            entry.day, entry.day, entry.part, entry.input
+        )
+        .unwrap();
+
+        sources.insert(source);
+    }
+
+    // Inputs are not part of the repository, so regenerate when one is added or removed.
+    for source in sources {
+        println!("cargo::rerun-if-changed={}", source.display());
+    }
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("answers.rs");
+    std::fs::write(out, tests).expect("generated tests should be writable");
+}
@@ -0,0 +1,99 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use aoc_core::{Solution, Solver};
+
+/// A solved day, together with the input that was embedded when it was built.
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    pub input: Option<&'static str>,
+}
+
+impl Day {
+    fn new<S: Solution>(input: Option<&'static str>) -> Self {
+        Day {
+            number: S::DAY,
+            solve: aoc_core::solve::<S>,
+            input,
+        }
+    }
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day1::Day1>(day1::INPUT),
+        Day::new::<day2::Day2>(day2::INPUT),
+        Day::new::<day3::Day3>(day3::INPUT),
+        Day::new::<day4::Day4>(day4::INPUT),
+        Day::new::<day5::Day5>(day5::INPUT),
+        Day::new::<day6::Day6>(day6::INPUT),
+        Day::new::<day7::Day7>(day7::INPUT),
+        Day::new::<day8::Day8>(day8::INPUT),
+        Day::new::<day9::Day9>(day9::INPUT),
+        Day::new::<day10::Day10>(day10::INPUT),
+        Day::new::<day11::Day11>(day11::INPUT),
+        Day::new::<day12::Day12>(day12::INPUT),
+        Day::new::<day13::Day13>(day13::INPUT),
+    ]
+}
+
+/// The days to run: `all`, or a comma-separated list of days (`5`) and ranges of days (`3-7`).
+#[derive(Clone, Debug)]
+pub enum Selection {
+    All,
+    Days(Vec<RangeInclusive<u8>>),
+}
+
+impl Selection {
+    /// Picks the selected days from `days`, failing when a selected day is not solved.
+    pub fn select(&self, days: Vec<Day>) -> Result<Vec<Day>> {
+        let Selection::Days(ranges) = self else {
+            return Ok(days);
+        };
+
+        if let Some(missing) = ranges
+            .iter()
+            .flat_map(Clone::clone)
+            .find(|number| days.iter().all(|day| day.number != *number))
+        {
+            bail!("Day {missing} is not solved");
+        }
+
+        Ok(days
+            .into_iter()
+            .filter(|day| ranges.iter().any(|range| range.contains(&day.number)))
+            .collect())
+    }
+}
+
+impl FromStr for Selection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+
+        s.split(',')
+            .map(parse_range)
+            .collect::<Result<Vec<RangeInclusive<u8>>>>()
+            .map(Selection::Days)
+    }
+}
+
+fn parse_range(s: &str) -> Result<RangeInclusive<u8>> {
+    let (start, end) = s.split_once('-').unwrap_or((s, s));
+    let start = start
+        .parse::<u8>()
+        .with_context(|| format!("{start} is not a day"))?;
+    let end = end
+        .parse::<u8>()
+        .with_context(|| format!("{end} is not a day"))?;
+    if start > end {
+        bail!("{s} is an empty range of days");
+    }
+
+    Ok(start..=end)
+}
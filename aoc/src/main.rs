@@ -0,0 +1,142 @@
+use anyhow::{bail, Result};
+use aoc::days::{self, Day, Selection};
+use aoc_core::{Format, Part, Report};
+use clap::{Parser, Subcommand};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug, Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Runs one or more days and prints a summary of the answers
+    Run {
+        /// `all`, or a comma-separated list of days (`5`) and ranges of days (`3-7`)
+        days: Selection,
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` for stdin; only allowed when running a single day
+        #[arg(long)]
+        input: Option<String>,
+        /// How to print the results: `human`, `json` (one object per line) or `csv`
+        #[arg(long, default_value = "human")]
+        format: Format,
+    },
+    /// Explains how day 7 orders the hands: their rank, what the jokers stand for, their position and winnings
+    Hands {
+        /// Only explain this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Only list the hands that are placed differently in part two than in part one
+        #[arg(long, conflicts_with = "part")]
+        diff: bool,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+        } => {
+            let part = part.map(Part::try_from).transpose()?;
+            run(&days, part, input.as_deref(), format)
+        }
+        Command::Hands { part, input, diff } => {
+            let part = part.map(Part::try_from).transpose()?;
+            hands(part, input.as_deref(), diff)
+        }
+    }
+}
+
+fn run(
+    selection: &Selection,
+    part: Option<Part>,
+    input: Option<&str>,
+    format: Format,
+) -> Result<()> {
+    let days = selection.select(days::all())?;
+    if input.is_some() && days.len() != 1 {
+        bail!("--input can only be used when running a single day");
+    }
+
+    let mut reports = vec![];
+    let mut failed = 0;
+    for (day, result) in days.iter().zip(run_days(&days, part, input)) {
+        match result {
+            Ok(report) => reports.push((day.number, report)),
+            Err(e) => {
+                eprintln!("Day {} failed: {e:#}", day.number);
+                failed += 1;
+            }
+        }
+    }
+
+    aoc_core::output::write(&mut std::io::stdout().lock(), format, &reports)?;
+    if failed > 0 {
+        bail!("{failed} of {} days failed", days.len());
+    }
+
+    Ok(())
+}
+
+/// Runs the days concurrently, keeping the results in the order of `days`.
+#[cfg(feature = "parallel")]
+fn run_days(days: &[Day], part: Option<Part>, input: Option<&str>) -> Vec<Result<Report>> {
+    days.par_iter()
+        .map(|day| run_day(day, part, input))
+        .collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn run_days(days: &[Day], part: Option<Part>, input: Option<&str>) -> Vec<Result<Report>> {
+    days.iter().map(|day| run_day(day, part, input)).collect()
+}
+
+fn hands(part: Option<Part>, input: Option<&str>, diff: bool) -> Result<()> {
+    let data = aoc_core::load(input, day7::INPUT)?;
+    let hands = day7::parse_input(&data)?;
+    let (standard, jokers) = (day7::Rules::standard(), day7::Rules::jokers());
+    let ranked = [
+        day7::RankedHands::new(&hands, &standard),
+        day7::RankedHands::new(&hands, &jokers),
+    ];
+
+    if diff {
+        for moved in day7::moves(&ranked[0], &ranked[1]) {
+            println!("{moved}");
+        }
+        return Ok(());
+    }
+
+    for (name, ranked) in [Part::One, Part::Two].into_iter().zip(&ranked) {
+        if part.is_some_and(|part| part != name) {
+            continue;
+        }
+        println!("Part {}", name.number());
+        for placement in ranked.placements() {
+            println!("{placement}");
+        }
+        println!("Total winnings: {}", ranked.total_winnings());
+    }
+
+    Ok(())
+}
+
+fn run_day(day: &Day, part: Option<Part>, input: Option<&str>) -> Result<Report> {
+    let data = aoc_core::load(input, day.input)?;
+
+    (day.solve)(&data, part)
+}
@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use aoc::days;
+use aoc_core::Part;
+
+/// Solves `part` of `day` for the given input file of that day and compares it to `expected`.
+fn check(day: u8, part: u8, input: &str, expected: &str) -> Result<()> {
+    let day = days::all()
+        .into_iter()
+        .find(|d| d.number == day)
+        .with_context(|| format!("Day {day} is not solved"))?;
+    let path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{}/src/{input}", day.number));
+    let data = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let report = (day.solve)(&data, Some(Part::try_from(part)?))?;
+    let (_, answer) = report.answers().next().context("No answer was computed")?;
+    assert_eq!(expected, answer.value);
+
+    Ok(())
+}
+
+include!(concat!(env!("OUT_DIR"), "/answers.rs"));
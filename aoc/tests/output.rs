@@ -0,0 +1,40 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{ensure, Result};
+
+/// Runs part one of `day` with the `aoc` binary on one of its input files and returns what it wrote to stdout.
+/// Part two of the days that used to print debug output is not solved yet.
+fn run(day: u8, input: &str, format: &str) -> Result<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{day}/src/{input}"));
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", &day.to_string(), "--part", "1", "--format", format])
+        .arg("--input")
+        .arg(path)
+        .output()?;
+    ensure!(output.status.success(), "Day {day} failed: {output:?}");
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[test]
+fn json_only_writes_records() -> Result<()> {
+    let stdout = run(10, "test3.txt", "json")?;
+
+    assert_eq!(1, stdout.lines().count(), "{stdout}");
+    assert!(stdout.starts_with(r#"{"day":10,"part":1,"#), "{stdout}");
+
+    Ok(())
+}
+
+#[test]
+fn csv_only_writes_records() -> Result<()> {
+    let stdout = run(13, "test.txt", "csv")?;
+
+    let mut lines = stdout.lines();
+    assert_eq!(Some("day,part,answer,parse_ns,solve_ns"), lines.next());
+    assert_eq!(Some("13,1,405,"), lines.next().map(|line| &line[..9]));
+    assert_eq!(None, lines.next());
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use anyhow::Result;
+use aoc_core::{ParseError, Solution};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+const DIGITS_AS_WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Calibration<'a>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(&input.digits()?))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_two(&input.digits_and_words()?))
+    }
+}
+
+/// The sum of the calibration values.
+pub fn part_one(input: &[u32]) -> u32 {
+    input.iter().sum()
+}
+
+/// The sum of the calibration values, with spelled out digits included.
+pub fn part_two(input: &[u32]) -> u32 {
+    input.iter().sum()
+}
+
+/// The calibration values of every line, read both from its digits alone and with spelled out digits included.
+/// A line without any digits only fails the part that reads it that way.
+#[derive(Debug)]
+pub struct Calibration<'a> {
+    input: &'a str,
+    lines: Vec<Line<'a>>,
+}
+
+#[derive(Debug)]
+struct Line<'a> {
+    text: &'a str,
+    digits: Option<u32>,
+    digits_and_words: Option<u32>,
+}
+
+impl Calibration<'_> {
+    /// The calibration value of every line from its digits.
+    pub fn digits(&self) -> Result<Vec<u32>> {
+        self.values(|line| line.digits)
+    }
+
+    /// The calibration value of every line from its digits and spelled out digits.
+    pub fn digits_and_words(&self) -> Result<Vec<u32>> {
+        self.values(|line| line.digits_and_words)
+    }
+
+    fn values(&self, value: impl Fn(&Line) -> Option<u32>) -> Result<Vec<u32>> {
+        self.lines
+            .iter()
+            .map(|line| {
+                value(line).ok_or_else(|| ParseError::at(self.input, line.text, "No digits").into())
+            })
+            .collect()
+    }
+}
+
+/// Reads the calibration value of every line in both ways.
+pub fn parse_input(input: &str) -> Result<Calibration<'_>> {
+    let lines = input
+        .lines()
+        .map(|text| Line {
+            text,
+            digits: parse_line(text, false),
+            digits_and_words: parse_line(text, true),
+        })
+        .collect();
+
+    Ok(Calibration { input, lines })
+}
+
+/// Parses the calibration value of every line from its digits.
+pub fn parse_input_one(input: &str) -> Result<Vec<u32>> {
+    parse_input(input)?.digits()
+}
+
+/// Parses the calibration value of every line from its digits and spelled out digits.
+pub fn parse_input_two(input: &str) -> Result<Vec<u32>> {
+    parse_input(input)?.digits_and_words()
+}
+
+/// Combines the first and last digit of `line`, or `None` when it has no digits.
+pub fn parse_line(line: &str, use_words: bool) -> Option<u32> {
+    let digits = (0..line.len())
+        .filter_map(|i| match_to_char(&line[i..], use_words))
+        .collect::<Vec<u32>>();
+
+    Some(digits.first()? * 10 + digits.last()?)
+}
+
+fn match_to_char(input: &str, use_words: bool) -> Option<u32> {
+    if let Some(first_char) = input.chars().next() {
+        if let Some(digit) = first_char.to_digit(10) {
+            return Some(digit);
+        }
+    }
+
+    if use_words {
+        if let Some(digit) = DIGITS_AS_WORDS
+            .iter()
+            .enumerate()
+            .find(|(_, word)| input.starts_with(*word))
+            .map(|(i, _)| i as u32)
+        {
+            return Some(digit);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibration() -> Result<()> {
+        let calibration = parse_input("1abc2\ntwonine\nxtwone3four")?;
+        assert_eq!(vec![12, 29, 24], calibration.digits_and_words()?);
+
+        let error = calibration.digits().unwrap_err().downcast::<ParseError>()?;
+        assert_eq!(
+            (2, 1, "No digits"),
+            (error.line, error.column, error.message.as_str())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,417 @@
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_possible_wrap)]
+#![allow(clippy::cast_sign_loss)]
+#![allow(dead_code)]
+#![allow(unused_variables)]
+
+use std::fmt::{Display, Formatter};
+
+use anyhow::{bail, Result};
+use aoc_core::{parse_grid, ParseError, Solution};
+use grid::{Coord, Grid};
+
+use crate::Type::{
+    Ground, Horizontal, NorthEast, NorthWest, SouthEast, SouthWest, Start, Vertical,
+};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Field;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        bail!("part two is not solved yet")
+    }
+}
+
+/// The number of steps to the point of the loop farthest from the start.
+pub fn part_one(input: &Field) -> usize {
+    let start = &input.start;
+    let mut current = *start;
+    let mut prev = None;
+    let mut count = 0usize;
+    loop {
+        count += 1;
+        let next = input.next(current, prev);
+        if next == *start {
+            return count / 2;
+        }
+        prev = Some(current);
+        current = next;
+    }
+}
+
+/// The number of tiles enclosed by the loop.
+fn part_two(input: &Field) -> usize {
+    let start = &input.start;
+    let mut current = *start;
+    let mut prev = None;
+    let mut poly = vec![];
+    loop {
+        // count += 1;
+        let next = input.next(current, prev);
+        poly.push(next);
+        if next == *start {
+            break;
+        }
+        prev = Some(current);
+        current = next;
+    }
+
+    let path = poly
+        .iter()
+        .map(|coord| (coord.x as isize, coord.y as isize))
+        .collect::<Vec<(isize, isize)>>();
+    let mut result = vec![];
+    for (Coord { x, y }, _) in input.tiles.iter() {
+        let res = is_point_in_path(x as isize, y as isize, &path);
+        if res != PathResult::Out {
+            result.push((x, y));
+        }
+    }
+
+    result.len() - poly.len() + 1
+}
+
+fn is_point_in_path(x: isize, y: isize, poly: &[(isize, isize)]) -> PathResult {
+    let num = poly.len();
+    let mut j = num - 1;
+    let mut c = false;
+    for i in 0..num {
+        if x == poly[i].0 && y == poly[i].1 {
+            // corner
+            return PathResult::Corner;
+        }
+        if (poly[i].1 > y) != (poly[j].1 > y) {
+            let slope = (x - poly[i].0) * (poly[j].1 - poly[i].1)
+                - (poly[j].0 - poly[i].0) * (y - poly[i].1);
+            if slope == 0 {
+                // boundary
+                return PathResult::Boundary;
+            }
+            if (slope < 0) != (poly[j].1 < poly[i].1) {
+                c = !c;
+                j = i;
+            }
+        }
+    }
+    if c {
+        PathResult::In
+    } else {
+        PathResult::Out
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum PathResult {
+    Boundary,
+    Corner,
+    In,
+    Out,
+}
+
+// def is_point_in_path(x: int, y: int, poly) -> bool:
+// """Determine if the point is on the path, corner, or boundary of the polygon
+//
+//     Args:
+//       x -- The x coordinates of point.
+//       y -- The y coordinates of point.
+//       poly -- a list of tuples [(x, y), (x, y), ...]
+//
+//     Returns:
+//       True if the point is in the path or is a corner or on the boundary"""
+// num = len(poly)
+// j = num - 1
+// c = False
+// for i in range(num):
+// if (x == poly[i][0]) and (y == poly[i][1]):
+// # point is a corner
+// return True
+// if (poly[i][1] > y) != (poly[j][1] > y):
+// slope = (x - poly[i][0]) * (poly[j][1] - poly[i][1]) - (
+// poly[j][0] - poly[i][0]
+// ) * (y - poly[i][1])
+// if slope == 0:
+// # point is on boundary
+// return True
+// if (slope < 0) != (poly[j][1] < poly[i][1]):
+// c = not c
+// j = i
+// return c
+
+/// The field of pipes, with the start tile replaced by the pipe that connects its neighbours.
+#[derive(Debug)]
+pub struct Field {
+    tiles: Grid<Type>,
+    start: Coord,
+}
+
+impl Field {
+    pub fn new(tiles: Grid<Type>, start: Coord) -> Self {
+        Field { tiles, start }
+    }
+
+    pub fn start(&self) -> Coord {
+        self.start
+    }
+
+    pub fn get(&self, coords: Coord) -> &Type {
+        &self.tiles[coords]
+    }
+
+    /// The tile the pipe at `current` leads to, moving away from `prev`.
+    pub fn next(&self, current: Coord, prev: Option<Coord>) -> Coord {
+        let modifiers = self.get(current).next();
+        let modifier = match prev {
+            None => modifiers[0],
+            Some(prev_coords) => {
+                if Some(prev_coords) == current.offset(modifiers[0][0], modifiers[0][1]) {
+                    modifiers[1]
+                } else {
+                    modifiers[0]
+                }
+            }
+        };
+
+        current
+            .offset(modifier[0], modifier[1])
+            .expect("the loop should stay inside the field")
+    }
+
+    /// The pipe that connects the start to its two neighbours, or `None` when it does not connect to exactly two.
+    fn start_tile(tiles: &Grid<Type>, start: Coord) -> Option<Type> {
+        const NORTH: usize = 1;
+        const EAST: usize = 2;
+        const SOUTH: usize = 4;
+        const WEST: usize = 8;
+        const NORTH_SOUTH: usize = NORTH + SOUTH;
+        const EAST_WEST: usize = EAST + WEST;
+        const NORTH_EAST: usize = NORTH + EAST;
+        const NORTH_WEST: usize = NORTH + WEST;
+        const SOUTH_WEST: usize = SOUTH + WEST;
+        const SOUTH_EAST: usize = SOUTH + EAST;
+
+        let neighbour = |dx, dy| {
+            start
+                .offset(dx, dy)
+                .and_then(|coord| tiles.get(coord))
+                .copied()
+        };
+        let north = Self::direction(neighbour(0, -1), [Vertical, SouthWest, SouthEast], NORTH);
+        let east = Self::direction(neighbour(1, 0), [Horizontal, SouthWest, NorthWest], EAST);
+        let south = Self::direction(neighbour(0, 1), [Vertical, NorthWest, NorthEast], SOUTH);
+        let west = Self::direction(neighbour(-1, 0), [Horizontal, SouthEast, NorthEast], WEST);
+
+        match north + east + south + west {
+            NORTH_SOUTH => Some(Vertical),
+            EAST_WEST => Some(Horizontal),
+            NORTH_EAST => Some(NorthEast),
+            NORTH_WEST => Some(NorthWest),
+            SOUTH_WEST => Some(SouthWest),
+            SOUTH_EAST => Some(SouthEast),
+            _ => None,
+        }
+    }
+
+    fn direction(tile: Option<Type>, valid: [Type; 3], default: usize) -> usize {
+        tile.filter(|x| valid.contains(x)).map_or(0, |_| default)
+    }
+
+    fn broaden(self) -> Self {
+        let start = Coord::new(self.start.x * 2, self.start.y * 2);
+        let mut tiles = vec![];
+        let rows = self.tiles.rows().collect::<Vec<&[Type]>>();
+        for (i, lines) in rows.windows(3).enumerate() {
+            let mut new_lines = vec![];
+            if i == 0 {
+                new_lines = Self::expand(&lines[0..1]);
+            }
+
+            new_lines.into_iter().for_each(|line| tiles.push(line));
+        }
+        // for y in 0..tiles.len() {
+        //     for x in 0..tiles.len() {
+        //
+        //     }
+        // }
+
+        Field {
+            tiles: Grid::from_rows(tiles).expect("expanded rows should have the same length"),
+            start,
+        }
+    }
+
+    fn expand(lines: &[&[Type]]) -> Vec<Vec<Type>> {
+        todo!()
+        // let mut new_lines = vec![];
+        // if lines.len() == 2 {
+        //     let mut new_line = vec![];
+        //     lines[0].windows(3).enumerate().for_each(|(i,t)| {
+        //         match (t[0], t[1], t[2]) {
+        //             (_, Vertical, _) => {}
+        //             Horizontal => {}
+        //             NorthEast => {}
+        //             NorthWest => {}
+        //             SouthWest => {}
+        //             SouthEast => {}
+        //             Ground => {}
+        //             Start => {}
+        //         }
+        //     });
+        //     new_lines.push(new_line);
+        // }
+        // new_lines
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.tiles)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Type {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl Type {
+    pub fn next(self) -> [[isize; 2]; 2] {
+        match self {
+            Vertical => [[0, -1], [0, 1]],
+            Horizontal => [[-1, 0], [1, 0]],
+            NorthEast => [[0, -1], [1, 0]],
+            NorthWest => [[0, -1], [-1, 0]],
+            SouthWest => [[0, 1], [-1, 0]],
+            SouthEast => [[0, 1], [1, 0]],
+            Ground => panic!(),
+            Start => panic!(),
+        }
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Vertical => '│',
+            Horizontal => '─',
+            NorthEast => '└',
+            NorthWest => '┘',
+            SouthWest => '┐',
+            SouthEast => '┌',
+            Ground => '.',
+            Start => 'S',
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl TryFrom<char> for Type {
+    type Error = ();
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Vertical),
+            '-' => Ok(Horizontal),
+            'L' => Ok(NorthEast),
+            'J' => Ok(NorthWest),
+            '7' => Ok(SouthWest),
+            'F' => Ok(SouthEast),
+            '.' => Ok(Ground),
+            'S' => Ok(Start),
+            _ => Err(()),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Field, ParseError> {
+    let mut tiles = parse_grid::<Type>(input)?;
+
+    let start = tiles
+        .position(|typ| typ == &Start)
+        .ok_or_else(|| ParseError::at(input, input, "There is no start tile"))?;
+    tiles[start] = Field::start_tile(&tiles, start).ok_or_else(|| {
+        ParseError::at_position(
+            input,
+            start.y + 1,
+            start.x + 1,
+            "The start does not connect to exactly two pipes",
+        )
+    })?;
+
+    Ok(Field::new(tiles, start))
+}
+
+/// Parses the field, locates the start and replaces it by the pipe that connects its neighbours.
+pub fn parse_input(input: &str) -> Result<Field> {
+    let input = parse(input)?;
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(input: &str) -> ParseError {
+        parse_input(input).unwrap_err().downcast().unwrap()
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let field = parse_input(include_str!("test.txt"))?;
+
+        assert_eq!(Coord::new(1, 1), field.start());
+        assert_eq!(&SouthEast, field.get(field.start()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_error("...\n...\n");
+        assert_eq!((1, 1), (error.line, error.column));
+        assert_eq!("There is no start tile", error.message);
+
+        let error = parse_error(".-.\n.S.\n");
+        assert_eq!((2, 2), (error.line, error.column));
+        assert_eq!(
+            "The start does not connect to exactly two pipes",
+            error.message
+        );
+
+        let error = parse_error("S-7\n|x|\n");
+        assert_eq!(
+            (2, 2, "x|"),
+            (error.line, error.column, error.snippet.as_str())
+        );
+        assert_eq!("Invalid cell 'x'", error.message);
+
+        let error = parse_error("S-7\n||\n");
+        assert_eq!((2, 1), (error.line, error.column));
+    }
+}
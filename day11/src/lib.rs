@@ -0,0 +1,138 @@
+use anyhow::Result;
+use aoc_core::{parse_grid, Solution};
+use bit_vec::BitVec;
+use grid::Coord;
+use itertools::Itertools;
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Galaxy;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_two(input))
+    }
+}
+
+/// The sum of the distances between all pairs of galaxies once empty space has doubled.
+pub fn part_one(input: &Galaxy) -> u64 {
+    let mut galaxy = input.clone();
+    galaxy.expand(2);
+    sum_manhattan_distance(&galaxy)
+}
+
+/// The sum of the distances between all pairs of galaxies once empty space has grown a million times.
+pub fn part_two(input: &Galaxy) -> u64 {
+    let mut galaxy = input.clone();
+    galaxy.expand(1_000_000);
+    // galaxy.expand(999_999);
+    sum_manhattan_distance(&galaxy)
+}
+
+/// The sum of the manhattan distances between all pairs of stars.
+pub fn sum_manhattan_distance(input: &Galaxy) -> u64 {
+    input
+        .stars
+        .iter()
+        .combinations(2)
+        .map(|v| v[0].manhattan_distance(*v[1]) as u64)
+        .sum()
+}
+
+/// The positions of the stars in an image of the size given.
+#[derive(Clone, Debug)]
+pub struct Galaxy {
+    stars: Vec<Coord>,
+    size: (usize, usize),
+}
+
+impl Galaxy {
+    pub fn stars(&self) -> &[Coord] {
+        &self.stars
+    }
+
+    /// Makes every row and column without stars `multiplier` times as large.
+    pub fn expand(&mut self, multiplier: usize) {
+        let mut new_cols = BitVec::from_elem(self.size.0, true);
+        let mut new_rows = BitVec::from_elem(self.size.1, true);
+        self.stars.iter().for_each(|star| {
+            new_cols.set(star.x, false);
+            new_rows.set(star.y, false);
+        });
+
+        for star in &mut self.stars {
+            star.x += (0..star.x).filter(|z| new_cols.get(*z).unwrap()).count() * (multiplier - 1);
+            star.y += (0..star.y).filter(|z| new_rows.get(*z).unwrap()).count() * (multiplier - 1);
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Galaxy> {
+    let grid = parse_grid::<char>(input)?;
+    let stars = grid
+        .iter()
+        .filter(|(_, c)| **c == '#')
+        .map(|(coord, _)| coord)
+        .collect();
+    let size = (grid.width(), grid.height());
+
+    Ok(Galaxy { stars, size })
+}
+
+/// Parses the image into the positions of its stars.
+pub fn parse_input(input: &str) -> Result<Galaxy> {
+    let input = parse(input)?;
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let mut galaxy = parse_input(TESTDATA)?;
+        galaxy.expand(10);
+        assert_eq!(1030, sum_manhattan_distance(&galaxy));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata2() -> Result<()> {
+        let mut galaxy = parse_input(TESTDATA)?;
+        galaxy.expand(100);
+        assert_eq!(8410, sum_manhattan_distance(&galaxy));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ragged_image() {
+        let error = parse_input("#..\n.#\n").unwrap_err();
+        assert_eq!(
+            "Expected 3 cells but found 2 at line 2, column 1: \".#\"",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use anyhow::{bail, Result};
+use aoc_core::{finish, Solution};
+use itertools::Itertools;
+use nom::character::complete;
+use nom::character::complete::one_of;
+use nom::combinator::map;
+use nom::multi::many1;
+use nom::sequence::separated_pair;
+use nom::{character::complete::line_ending, multi::separated_list1, IResult};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<Row>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        bail!("part two is not solved yet")
+    }
+}
+
+/// The sum of the possible arrangements of every row.
+pub fn part_one(input: &[Row]) -> u64 {
+    rows(input).map(solve).sum()
+}
+
+#[cfg(feature = "parallel")]
+fn rows(input: &[Row]) -> rayon::slice::Iter<'_, Row> {
+    input.par_iter()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn rows(input: &[Row]) -> std::slice::Iter<'_, Row> {
+    input.iter()
+}
+
+/// The number of ways the unknown springs of `row` can be filled in to match its group sizes.
+pub fn solve(row: &Row) -> u64 {
+    let marks = row
+        .content
+        .char_indices()
+        .filter_map(|(i, c)| if c == '?' { Some(i) } else { None })
+        .collect::<Vec<usize>>();
+
+    let mut count = 0;
+    for r in (0..marks.len())
+        .map(|_| ".#".chars())
+        .multi_cartesian_product()
+    {
+        let new_str = replace_marks(row, &marks, &r);
+        if calculate_bit_vec_sizes(new_str) == row.sizes {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn replace_marks(row: &Row, marks: &[usize], replacements: &[char]) -> u64 {
+    let mut idx = 0;
+    let mut next_mark = marks.get(idx);
+    row.content
+        .char_indices()
+        .map(|(i, c)| match next_mark {
+            Some(j) if i == *j => {
+                idx += 1;
+                next_mark = marks.get(idx);
+                (i, replacements[idx - 1])
+            }
+            _ => (i, c),
+        })
+        .fold(0, |acc, (i, c)| if c == '#' { acc | 1 << i } else { acc })
+}
+
+fn calculate_bit_vec_sizes(input: u64) -> Vec<usize> {
+    let mut number = input;
+    let mut result = vec![];
+    let mut count = 0usize;
+    loop {
+        if number & 1 == 1 {
+            count += 1;
+        } else if count > 0 {
+            result.push(count);
+            count = 0;
+        }
+        if number == 0 {
+            return result;
+        }
+        number >>= 1;
+    }
+}
+
+/// A row of springs, with `?` for unknown ones, and the sizes of its groups of damaged springs.
+#[derive(Debug)]
+pub struct Row {
+    content: String,
+    sizes: Vec<usize>,
+}
+
+impl Row {
+    pub fn new(content: String, sizes: Vec<usize>) -> Self {
+        Row { content, sizes }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Row>> {
+    separated_list1(line_ending, parse_row)(input)
+}
+
+fn parse_row(input: &str) -> IResult<&str, Row> {
+    map(
+        separated_pair(parse_content, complete::char(' '), parse_sizes),
+        |(content, sizes)| Row::new(content, sizes),
+    )(input)
+}
+
+fn parse_content(input: &str) -> IResult<&str, String> {
+    map(many1(one_of(".#?")), |v| v.iter().collect::<String>())(input)
+}
+
+fn parse_sizes(input: &str) -> IResult<&str, Vec<usize>> {
+    separated_list1(complete::char(','), map(complete::u8, |v| v as usize))(input)
+}
+
+/// Parses one row per line.
+pub fn parse_input(input: &str) -> Result<Vec<Row>> {
+    let input = finish(input, parse(input))?;
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_bit_vec_sizes() -> Result<()> {
+        // ###.#
+        assert_eq!(vec![3, 1], calculate_bit_vec_sizes(23));
+        // #.#.###
+        assert_eq!(vec![1, 1, 3], calculate_bit_vec_sizes(117));
+        // ###.###
+        assert_eq!(vec![3, 3], calculate_bit_vec_sizes(119));
+        Ok(())
+    }
+
+    #[test]
+    fn test_row() -> Result<()> {
+        assert_eq!(1, solve(&parse_row("???.### 1,1,3")?.1));
+        assert_eq!(4, solve(&parse_row(".??..??...?##. 1,1,3")?.1));
+        assert_eq!(1, solve(&parse_row("?#?#?#?#?#?#?#? 1,3,1,6")?.1));
+        assert_eq!(1, solve(&parse_row("????.#...#... 4,1,1")?.1));
+        assert_eq!(4, solve(&parse_row("????.######..#####. 1,6,5")?.1));
+        assert_eq!(10, solve(&parse_row("?###???????? 3,2,1")?.1));
+        Ok(())
+    }
+}
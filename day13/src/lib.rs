@@ -0,0 +1,257 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+
+use anyhow::{bail, Result};
+use aoc_core::{finish, Solution};
+use grid::{Coord, Grid};
+use nom::{
+    character::{complete::line_ending, complete::one_of},
+    combinator::{map, map_res},
+    multi::{many1, separated_list1},
+    sequence::pair,
+    IResult,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Pattern>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        bail!("part two is not solved yet")
+    }
+}
+
+fn part_one_a(input: &[Pattern]) -> u32 {
+    let mut count = 0;
+    for pattern in input {
+        let rows = pattern.lines.rows().collect::<Vec<&[char]>>();
+        let horizontal = rows.windows(2).enumerate().find_map(|(i, v)| {
+            if v[0] == v[1] {
+                Some((i - 1) as u32 * 100)
+            } else {
+                None
+            }
+        });
+        count += horizontal.unwrap_or_else(|| {
+            let lines = pattern.lines.transpose();
+            let columns = lines.rows().collect::<Vec<&[char]>>();
+            columns
+                .windows(2)
+                .enumerate()
+                .find_map(|(i, v)| if v[0] == v[1] { Some(i as u32) } else { None })
+                .unwrap()
+        });
+    }
+    count
+}
+
+/// The summary of the mirror lines: columns left of each vertical mirror plus 100 times the rows above each
+/// horizontal mirror.
+pub fn part_one(input: &[Pattern]) -> u32 {
+    patterns(input)
+        .map(|pattern| {
+            pattern
+                .find_horizontal_mirror()
+                .map(|h| h * 100)
+                .or(pattern.find_vertical_mirror())
+                .unwrap()
+        })
+        .sum()
+}
+
+fn part_two(input: &[Pattern]) -> u32 {
+    let Some(pattern) = input.get(1) else {
+        return 0;
+    };
+    let _result = pattern.find_almost_mirror();
+    // let columns = Pattern::find_almost_mirror(&pattern.columns);
+
+    // println!("Columns: {columns:?}");
+
+    0
+}
+
+#[cfg(feature = "parallel")]
+fn patterns(input: &[Pattern]) -> rayon::slice::Iter<'_, Pattern> {
+    input.par_iter()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn patterns(input: &[Pattern]) -> std::slice::Iter<'_, Pattern> {
+    input.iter()
+}
+
+/// A pattern of ash and rocks, with every row and column also encoded as a number to compare them cheaply.
+#[derive(Debug)]
+pub struct Pattern {
+    lines: Grid<char>,
+    rows: Vec<u32>,
+    columns: Vec<u32>,
+}
+
+impl Pattern {
+    pub fn new(lines: Grid<char>) -> Self {
+        let columns = Self::transform(&lines.transpose());
+        let rows = Self::transform(&lines);
+
+        Pattern {
+            lines,
+            rows,
+            columns,
+        }
+    }
+
+    /// The pattern with rocks as `'1'` and ash as `'0'`.
+    pub fn lines(&self) -> &Grid<char> {
+        &self.lines
+    }
+
+    /// The number of rows above the horizontal mirror line, if there is one.
+    pub fn find_horizontal_mirror(&self) -> Option<u32> {
+        Self::find_mirror(&self.rows)
+        // Self::find_mirror_char(&self.lines)
+    }
+
+    /// The number of columns left of the vertical mirror line, if there is one.
+    pub fn find_vertical_mirror(&self) -> Option<u32> {
+        Self::find_mirror(&self.columns)
+        // Self::find_mirror_char(&lines)
+    }
+
+    /// The position of the first line in `input` between two reflecting halves, counted as the number of items
+    /// before it. The reflection stops at whichever edge is reached first.
+    pub fn find_mirror(input: &[u32]) -> Option<u32> {
+        for i in 1..input.len() {
+            let (front, back) = input.split_at(i);
+            // println!("Comparing {:?} and {:?}", front, back);
+            if front.last() == back.first() {
+                let mut back = back.to_vec();
+                back.reverse();
+                // println!("Reversed {:?} and {:?}", front, back);
+                if front.ends_with(&back) || back.ends_with(front) {
+                    return Some(i as u32);
+                }
+            }
+        }
+
+        None
+    }
+
+    // fn find_mirror_char(input: &[Vec<char>]) -> Option<u32> {
+    //     for i in 1..input.len() {
+    //         let (front, back) = input.split_at(i);
+    //         // println!("Comparing {:?} and {:?}", front, back);
+    //         if front.last() == back.first() {
+    //             let mut back = back.to_vec();
+    //             back.reverse();
+    //             // println!("Reversed {:?} and {:?}", front, back);
+    //             let diff = diff_with(front.into_iter(), back.into_iter(), |a,b| a == &b);
+    //             match diff {
+    //                 None => return None,
+    //                 Some(diff) => {
+    //                     println!("{}", diff);
+    //                     return None;
+    //                 }
+    //             }
+    //             if front.ends_with(&back) || back.ends_with(front) {
+    //                 return Some(i as u32);
+    //             }
+    //         }
+    //     }
+    //
+    //     None
+    // }
+
+    pub fn find_almost_mirror(&self) -> Option<(usize, usize, Option<u32>)> {
+        let existing = self
+            .find_horizontal_mirror()
+            .map(|h| h * 100)
+            .or(self.find_vertical_mirror());
+        for y in 0..self.lines.height() {
+            for x in 0..self.lines.width() {
+                let mut lines = self.lines.clone();
+                let c = lines.get_mut(Coord::new(x, y)).unwrap();
+                if c == &'1' {
+                    *c = '0';
+                } else {
+                    *c = '1';
+                }
+                let pattern = Pattern::new(lines);
+                let new = pattern
+                    .find_horizontal_mirror()
+                    .map(|h| h * 100)
+                    .or(pattern.find_vertical_mirror());
+                if new.is_some() && new != existing {
+                    return Some((x, y, new));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn find_smudge(long: &[u32], short: &[u32]) -> Option<(u32, u32)> {
+        let mut smudge = None;
+        for i in 0..short.len() {
+            if short[i] != long[long.len() - 1 - i] {
+                if smudge.is_none() {
+                    smudge = Some((short[i], long[long.len() - 1 - i]));
+                } else {
+                    return None;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn transform(v: &Grid<char>) -> Vec<u32> {
+        v.rows()
+            .map(|line| {
+                let string = line.iter().collect::<String>();
+                u32::from_str_radix(string.as_str(), 2).unwrap()
+            })
+            .collect::<Vec<u32>>()
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Pattern>> {
+    separated_list1(pair(line_ending, line_ending), parse_pattern)(input)
+}
+
+fn parse_pattern(input: &str) -> IResult<&str, Pattern> {
+    map_res(separated_list1(line_ending, parse_line), |v| {
+        Grid::from_rows(v).map(Pattern::new)
+    })(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<char>> {
+    many1(map(one_of(".#"), |c| if c == '.' { '0' } else { '1' }))(input)
+}
+
+/// Parses the blank line separated patterns.
+pub fn parse_input(input: &str) -> Result<Vec<Pattern>> {
+    let input = finish(input, parse(input))?;
+
+    Ok(input)
+}
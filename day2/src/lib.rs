@@ -0,0 +1,148 @@
+use anyhow::Result;
+use aoc_core::{finish, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{delimited, pair, terminated},
+    IResult,
+};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_two(input))
+    }
+}
+
+/// The sum of the ids of the games that are possible with 12 red, 13 green and 14 blue cubes.
+pub fn part_one(input: &[Game]) -> u32 {
+    input
+        .iter()
+        .filter(|game| game.max_red() <= 12 && game.max_green() <= 13 && game.max_blue() <= 14)
+        .map(|game| game.id)
+        .sum()
+}
+
+/// The sum of the powers of the smallest set of cubes that makes each game possible.
+pub fn part_two(input: &[Game]) -> u32 {
+    input
+        .iter()
+        .map(|game| game.max_red() * game.max_green() * game.max_blue())
+        .sum()
+}
+
+/// A game with the cubes revealed in each of its rounds.
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn max_red(&self) -> u32 {
+        self.rounds.iter().map(|r| r.red).max().unwrap_or_default()
+    }
+
+    pub fn max_green(&self) -> u32 {
+        self.rounds
+            .iter()
+            .map(|r| r.green)
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn max_blue(&self) -> u32 {
+        self.rounds.iter().map(|r| r.blue).max().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Round {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+#[derive(Debug)]
+enum Cube {
+    Red(u32),
+    Green(u32),
+    Blue(u32),
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Game>> {
+    separated_list1(line_ending, parse_line)(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Game> {
+    map(
+        pair(parse_id, separated_list1(tag("; "), parse_round)),
+        |(id, rounds)| Game { id, rounds },
+    )(input)
+}
+
+fn parse_id(input: &str) -> IResult<&str, u32> {
+    delimited(tag("Game "), complete::u32, tag(": "))(input)
+}
+
+fn parse_round(input: &str) -> IResult<&str, Round> {
+    map(
+        separated_list1(tag(", "), alt((parse_red, parse_blue, parse_green))),
+        |v| {
+            v.iter().fold(Round::default(), |mut round, cube| {
+                match cube {
+                    Cube::Red(c) => round.red += c,
+                    Cube::Blue(c) => round.blue += c,
+                    Cube::Green(c) => round.green += c,
+                };
+                round
+            })
+        },
+    )(input)
+}
+
+fn parse_red(input: &str) -> IResult<&str, Cube> {
+    map(terminated(complete::u32, tag(" red")), Cube::Red)(input)
+}
+
+fn parse_green(input: &str) -> IResult<&str, Cube> {
+    map(terminated(complete::u32, tag(" green")), Cube::Green)(input)
+}
+
+fn parse_blue(input: &str) -> IResult<&str, Cube> {
+    map(terminated(complete::u32, tag(" blue")), Cube::Blue)(input)
+}
+
+/// Parses one game per line.
+pub fn parse_input(input: &str) -> Result<Vec<Game>> {
+    let input = finish(input, parse(input))?;
+
+    Ok(input)
+}
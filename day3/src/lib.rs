@@ -0,0 +1,164 @@
+use ahash::{HashMap, HashMapExt, HashSet};
+
+use anyhow::Result;
+use aoc_core::{parse_grid, Solution};
+use grid::{Coord, Grid};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = EngineMap;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_two(input))
+    }
+}
+
+/// The sum of the part numbers, the numbers adjacent to a symbol.
+pub fn part_one(input: &EngineMap) -> u32 {
+    input
+        .numbers
+        .iter()
+        .filter(|number| {
+            number
+                .adjacent(&input.grid)
+                .iter()
+                .any(|coord| is_symbol(input.grid[*coord]))
+        })
+        .map(|number| number.value)
+        .sum()
+}
+
+/// The sum of the gear ratios of every `*` adjacent to exactly two numbers.
+pub fn part_two(input: &EngineMap) -> u32 {
+    let mut gears: HashMap<Coord, Vec<u32>> = HashMap::new();
+    for number in &input.numbers {
+        for coord in number.adjacent(&input.grid) {
+            if input.grid[coord] == '*' {
+                gears.entry(coord).or_default().push(number.value);
+            }
+        }
+    }
+
+    gears
+        .values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| numbers.iter().product::<u32>())
+        .sum()
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+/// The engine schematic and the numbers found in it.
+#[derive(Debug)]
+pub struct EngineMap {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+impl EngineMap {
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+}
+
+/// A number in the schematic, positioned by its first digit.
+#[derive(Debug)]
+pub struct Number {
+    value: u32,
+    length: usize,
+    position: Coord,
+}
+
+impl Number {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn position(&self) -> Coord {
+        self.position
+    }
+
+    /// The cells touching any of the number's digits, diagonally included.
+    pub fn adjacent(&self, grid: &Grid<char>) -> HashSet<Coord> {
+        self.digits()
+            .flat_map(|digit| grid.neighbours8(digit))
+            .filter(|coord| !self.digits().any(|digit| digit == *coord))
+            .collect()
+    }
+
+    fn digits(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.length).map(|i| Coord::new(self.position.x + i, self.position.y))
+    }
+}
+
+fn parse(input: &str) -> Result<EngineMap> {
+    let grid = parse_grid::<char>(input)?;
+    let mut numbers: Vec<Number> = vec![];
+    for (row, line) in grid.rows().enumerate() {
+        let mut number_chars = vec![];
+        let mut number_pos = Coord::new(0, row);
+        for (col, c) in line.iter().enumerate() {
+            if c.is_ascii_digit() {
+                if number_chars.is_empty() {
+                    number_pos = Coord::new(col, row);
+                }
+                number_chars.push(*c);
+            } else if !number_chars.is_empty() {
+                handle_end_of_number(&mut number_chars, &mut numbers, number_pos)?;
+            }
+        }
+        if !number_chars.is_empty() {
+            handle_end_of_number(&mut number_chars, &mut numbers, number_pos)?;
+        }
+    }
+
+    Ok(EngineMap { grid, numbers })
+}
+
+fn handle_end_of_number(
+    number_chars: &mut Vec<char>,
+    numbers: &mut Vec<Number>,
+    number_pos: Coord,
+) -> Result<()> {
+    let value = number_chars.iter().collect::<String>().parse::<u32>()?;
+    let length = number_chars.len();
+    numbers.push(Number {
+        value,
+        length,
+        position: number_pos,
+    });
+    number_chars.clear();
+
+    Ok(())
+}
+
+/// Parses the schematic and locates its numbers.
+pub fn parse_input(input: &str) -> Result<EngineMap> {
+    let input = parse(input)?;
+
+    Ok(input)
+}
@@ -0,0 +1,125 @@
+use anyhow::Result;
+use aoc_core::{finish, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending, space0, space1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair},
+    IResult,
+};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_two(input))
+    }
+}
+
+/// The total points of the cards, doubling for every match after the first.
+pub fn part_one(input: &[Card]) -> u32 {
+    input
+        .iter()
+        .map(|card| match card.num_matches {
+            0 => 0,
+            num_matches => 2u32.pow(num_matches - 1),
+        })
+        .sum()
+}
+
+/// The total number of scratchcards once every match has won copies of the following cards.
+pub fn part_two(input: &[Card]) -> usize {
+    let mut number_of_cards = 0;
+    let mut cards = input.iter().map(|card| card.id).collect::<Vec<u32>>();
+    loop {
+        let mut added_cards: Vec<u32> = vec![];
+        cards.iter().for_each(|card_id| {
+            match input.get(*card_id as usize - 1).unwrap().num_matches {
+                0 => {}
+                num_matches => (0..num_matches).for_each(|i| {
+                    added_cards.push(card_id + 1 + i);
+                }),
+            }
+        });
+        number_of_cards += cards.len();
+        if added_cards.is_empty() {
+            return number_of_cards;
+        }
+        cards = added_cards;
+    }
+}
+
+/// A scratchcard, reduced to how many of its numbers are winning numbers.
+#[derive(Debug)]
+pub struct Card {
+    id: u32,
+    num_matches: u32,
+}
+
+impl Card {
+    pub fn new(id: u32, winning_numbers: Vec<u32>, my_numbers: Vec<u32>) -> Self {
+        let num_matches = my_numbers
+            .iter()
+            .filter(|mine| winning_numbers.contains(mine))
+            .count() as u32;
+        Self { id, num_matches }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn num_matches(&self) -> u32 {
+        self.num_matches
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Card>> {
+    separated_list1(line_ending, parse_line)(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Card> {
+    map(
+        separated_pair(parse_id, tag(": "), parse_number_groups),
+        |(id, (my_numbers, winning_numbers))| Card::new(id, my_numbers, winning_numbers),
+    )(input)
+}
+
+fn parse_id(input: &str) -> IResult<&str, u32> {
+    preceded(pair(tag("Card"), space1), complete::u32)(input)
+}
+
+fn parse_number_groups(input: &str) -> IResult<&str, (Vec<u32>, Vec<u32>)> {
+    separated_pair(parse_numbers, tag(" | "), parse_numbers)(input)
+}
+
+fn parse_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    preceded(space0, separated_list1(space1, complete::u32))(input)
+}
+
+/// Parses one card per line.
+pub fn parse_input(input: &str) -> Result<Vec<Card>> {
+    let input = finish(input, parse(input))?;
+
+    Ok(input)
+}
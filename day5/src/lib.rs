@@ -0,0 +1,660 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use anyhow::{bail, Context, Result};
+use aoc_core::{finish, ParseError, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1, line_ending, space1},
+    combinator::{consumed, eof, map},
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair, separated_pair, terminated, tuple},
+    IResult,
+};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+/// The lowest location of any of the seeds.
+pub fn part_one(input: &Almanac) -> Result<u64> {
+    let conversion = input.conversion("seed", "location")?;
+
+    Ok(input
+        .seeds
+        .iter()
+        .map(|seed| conversion.get(*seed))
+        .min()
+        .unwrap())
+}
+
+/// The lowest location of any seed in the seed ranges.
+pub fn part_two(input: &Almanac) -> Result<u64> {
+    let conversion = input.conversion("seed", "location")?;
+
+    input
+        .seed_ranges()?
+        .into_iter()
+        .flat_map(|range| conversion.get_range(range))
+        .map(|range| range.start)
+        .min()
+        .context("There are no seeds to plant")
+}
+
+/// The seeds to plant and the maps converting between the categories of the almanac.
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<CategoryMap>,
+}
+
+impl Almanac {
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
+
+    /// The seeds read as pairs of a start and a length. Fails when a seed has no length, or when a range does not
+    /// fit in a `u64`.
+    pub fn seed_ranges(&self) -> Result<Vec<Range<u64>>> {
+        let pairs = self.seeds.chunks_exact(2);
+        if let [seed] = pairs.remainder() {
+            bail!("Seed {seed} has no length");
+        }
+
+        pairs
+            .map(|pair| match pair[0].checked_add(pair[1]) {
+                Some(end) => Ok(pair[0]..end),
+                None => bail!(
+                    "Seed range of length {} from {} overflows",
+                    pair[1],
+                    pair[0]
+                ),
+            })
+            .collect()
+    }
+
+    /// The maps in the order they appear in the almanac.
+    pub fn maps(&self) -> &[CategoryMap] {
+        &self.maps
+    }
+
+    /// The categories in the order they first appear in the almanac.
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories = vec![];
+        for map in &self.maps {
+            for category in [map.from.as_str(), map.to.as_str()] {
+                if !categories.contains(&category) {
+                    categories.push(category);
+                }
+            }
+        }
+
+        categories
+    }
+
+    /// The shortest chain of maps converting `from` into `to`, failing when there is none.
+    pub fn conversion(&self, from: &str, to: &str) -> Result<Conversion<'_>> {
+        let mut previous: HashMap<&str, Option<&CategoryMap>> = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                let mut maps = vec![];
+                let mut current = to;
+                while let Some(map) = previous[current] {
+                    maps.push(&map.map);
+                    current = &map.from;
+                }
+                maps.reverse();
+
+                return Ok(Conversion { maps });
+            }
+
+            for map in self.maps.iter().filter(|map| map.from == category) {
+                if !previous.contains_key(map.to.as_str()) {
+                    previous.insert(&map.to, Some(map));
+                    queue.push_back(&map.to);
+                }
+            }
+        }
+
+        bail!("There is no conversion from {from} to {to}")
+    }
+
+    /// The ranges of `from` numbers that are converted into a `to` number in `range`.
+    pub fn inverse(&self, from: &str, to: &str, range: Range<u64>) -> Result<Vec<Range<u64>>> {
+        Ok(self.conversion(from, to)?.inverse_range(range))
+    }
+}
+
+/// A map section of the almanac, converting numbers of the `from` category into the `to` category.
+#[derive(Debug)]
+pub struct CategoryMap {
+    from: String,
+    to: String,
+    map: AlmanacMap,
+}
+
+impl CategoryMap {
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+
+    pub fn map(&self) -> &AlmanacMap {
+        &self.map
+    }
+}
+
+/// The maps leading from one category to another, applied in order.
+#[derive(Debug)]
+pub struct Conversion<'a> {
+    maps: Vec<&'a AlmanacMap>,
+}
+
+impl Conversion<'_> {
+    pub fn get(&self, key: u64) -> u64 {
+        self.maps.iter().fold(key, |key, map| map.get(key))
+    }
+
+    /// The ranges all numbers in `range` are converted into.
+    pub fn get_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        self.maps.iter().fold(vec![range], |ranges, map| {
+            ranges
+                .into_iter()
+                .flat_map(|range| map.get_range(range))
+                .collect()
+        })
+    }
+
+    /// The ranges of numbers that are converted into a number in `range`, ordered and merged.
+    pub fn inverse_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        self.maps.iter().rev().fold(vec![range], |ranges, map| {
+            merge_ranges(
+                ranges
+                    .into_iter()
+                    .flat_map(|range| map.inverse_range(range))
+                    .collect(),
+            )
+        })
+    }
+
+    /// All maps composed into a single map.
+    pub fn compose(&self) -> AlmanacMap {
+        self.maps
+            .iter()
+            .fold(AlmanacMap::identity(), |composed, map| {
+                composed.compose(map)
+            })
+    }
+}
+
+/// Maps the numbers of one category onto the next. Its segments cover all numbers in order, with the gaps between the
+/// ranges of the almanac as segments that map onto themselves.
+#[derive(Debug)]
+pub struct AlmanacMap {
+    segments: Vec<AlmanacRange>,
+}
+
+/// A contiguous source range that is shifted onto the destination range starting at `base`.
+#[derive(Clone, Debug)]
+pub struct AlmanacRange {
+    range: Range<u64>,
+    base: u64,
+}
+
+impl AlmanacRange {
+    /// Fails when the source or the destination range does not fit in a `u64`.
+    pub fn new(destination: u64, source: u64, length: u64) -> Result<Self> {
+        match (source.checked_add(length), destination.checked_add(length)) {
+            (Some(end), Some(_)) => Ok(AlmanacRange {
+                range: source..end,
+                base: destination,
+            }),
+            _ => bail!("Range of length {length} from {source} to {destination} overflows"),
+        }
+    }
+
+    pub fn source(&self) -> &Range<u64> {
+        &self.range
+    }
+
+    pub fn destination(&self) -> u64 {
+        self.base
+    }
+
+    fn identity(range: Range<u64>) -> Self {
+        let base = range.start;
+        AlmanacRange { range, base }
+    }
+
+    fn len(&self) -> u64 {
+        self.range.end - self.range.start
+    }
+
+    fn is_identity(&self) -> bool {
+        self.range.start == self.base
+    }
+
+    /// Shifts `range`, which must lie within the source range, onto the destination.
+    fn shift(&self, range: Range<u64>) -> Range<u64> {
+        (self.base + (range.start - self.range.start))..(self.base + (range.end - self.range.start))
+    }
+}
+
+impl AlmanacMap {
+    /// Orders the ranges and fills the gaps between them, failing when two source ranges overlap.
+    pub fn new(mut ranges: Vec<AlmanacRange>) -> Result<Self> {
+        ranges.retain(|r| !r.range.is_empty());
+        ranges.sort_by_key(|r| r.range.start);
+        if let Some(pair) = ranges
+            .windows(2)
+            .find(|pair| pair[1].range.start < pair[0].range.end)
+        {
+            bail!(
+                "Source ranges {:?} and {:?} overlap",
+                pair[0].range,
+                pair[1].range
+            );
+        }
+
+        let mut segments = vec![];
+        let mut cursor = 0;
+        for r in ranges {
+            if cursor < r.range.start {
+                segments.push(AlmanacRange::identity(cursor..r.range.start));
+            }
+            cursor = r.range.end;
+            segments.push(r);
+        }
+        if cursor < u64::MAX {
+            segments.push(AlmanacRange::identity(cursor..u64::MAX));
+        }
+
+        Ok(AlmanacMap { segments })
+    }
+
+    /// The map that maps every number onto itself.
+    pub fn identity() -> Self {
+        AlmanacMap {
+            segments: vec![AlmanacRange::identity(0..u64::MAX)],
+        }
+    }
+
+    /// The consecutive segments covering all numbers, ordered by source.
+    pub fn segments(&self) -> &[AlmanacRange] {
+        &self.segments
+    }
+
+    /// The segments that do not map onto themselves.
+    pub fn ranges(&self) -> impl Iterator<Item = &AlmanacRange> {
+        self.segments.iter().filter(|r| !r.is_identity())
+    }
+
+    pub fn get(&self, key: u64) -> u64 {
+        let index = self.segments.partition_point(|s| s.range.end <= key);
+        self.segments
+            .get(index)
+            .map_or(key, |s| s.base + (key - s.range.start))
+    }
+
+    /// Maps all numbers in `range`, splitting it into a separate range for every part that is mapped differently.
+    pub fn get_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        self.overlapping(range)
+            .map(|(segment, range)| segment.shift(range))
+            .collect()
+    }
+
+    /// The ranges of keys that map onto a number in `range`, ordered and merged.
+    pub fn inverse_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        merge_ranges(
+            self.segments
+                .iter()
+                .filter_map(|s| {
+                    let start = range.start.max(s.base);
+                    let end = range.end.min(s.base + s.len());
+                    (start < end).then(|| {
+                        (s.range.start + (start - s.base))..(s.range.start + (end - s.base))
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// The keys at which a new segment starts.
+    pub fn breakpoints(&self) -> Vec<u64> {
+        self.segments.iter().map(|s| s.range.start).collect()
+    }
+
+    /// The lowest number any key in `range` maps onto, or `None` if `range` is empty.
+    pub fn min_over(&self, range: Range<u64>) -> Option<u64> {
+        self.overlapping(range)
+            .map(|(segment, range)| segment.shift(range).start)
+            .min()
+    }
+
+    /// A single map that is equivalent to applying this map and then `next`.
+    pub fn compose(&self, next: &AlmanacMap) -> AlmanacMap {
+        let mut segments: Vec<AlmanacRange> = vec![];
+        for segment in &self.segments {
+            let destination = segment.base..(segment.base + segment.len());
+            for (n, range) in next.overlapping(destination) {
+                let source = segment.range.start + (range.start - segment.base);
+                let base = n.shift(range.clone()).start;
+                let length = range.end - range.start;
+                match segments.last_mut() {
+                    Some(last) if last.range.end == source && last.base + last.len() == base => {
+                        last.range.end += length;
+                    }
+                    _ => segments.push(AlmanacRange {
+                        range: source..(source + length),
+                        base,
+                    }),
+                }
+            }
+        }
+
+        AlmanacMap { segments }
+    }
+
+    /// The segments overlapping `range`, each with the part of `range` it covers.
+    fn overlapping(
+        &self,
+        range: Range<u64>,
+    ) -> impl Iterator<Item = (&AlmanacRange, Range<u64>)> + '_ {
+        let first = self
+            .segments
+            .partition_point(|s| s.range.end <= range.start);
+        self.segments[first..]
+            .iter()
+            .take_while(move |s| s.range.start < range.end)
+            .map(move |s| {
+                (
+                    s,
+                    range.start.max(s.range.start)..range.end.min(s.range.end),
+                )
+            })
+            .filter(|(_, range)| !range.is_empty())
+    }
+}
+
+fn merge_ranges(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range<u64>> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+impl Display for AlmanacMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for r in self.ranges() {
+            writeln!(f, "{} {} {}", r.base, r.range.start, r.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A map section as it appears in the almanac, before its ranges are validated.
+struct Section<'a> {
+    header: &'a str,
+    from: &'a str,
+    to: &'a str,
+    lines: Vec<(&'a str, (u64, u64, u64))>,
+}
+
+impl Section<'_> {
+    fn into_map(self, input: &str) -> Result<CategoryMap> {
+        let ranges = self
+            .lines
+            .iter()
+            .map(|(line, (destination, source, length))| {
+                AlmanacRange::new(*destination, *source, *length)
+                    .map_err(|e| ParseError::at(input, line, e.to_string()).into())
+            })
+            .collect::<Result<Vec<AlmanacRange>>>()?;
+        let map = AlmanacMap::new(ranges)
+            .map_err(|e| ParseError::at(input, self.header, e.to_string()))?;
+
+        Ok(CategoryMap {
+            from: self.from.to_string(),
+            to: self.to.to_string(),
+            map,
+        })
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, (Vec<u64>, Vec<Section<'_>>)> {
+    pair(parse_seeds, many1(parse_map))(input)
+}
+
+fn parse_seeds(input: &str) -> IResult<&str, Vec<u64>> {
+    delimited(
+        tag("seeds: "),
+        separated_list1(space1, complete::u64),
+        line_ending,
+    )(input)
+}
+
+fn parse_map(input: &str) -> IResult<&str, Section<'_>> {
+    let (input, (header, (from, to))) = delimited(
+        line_ending,
+        consumed(separated_pair(alpha1, tag("-to-"), alpha1)),
+        pair(tag(" map:"), line_ending),
+    )(input)?;
+    let (input, lines) = terminated(
+        separated_list1(line_ending, consumed(parse_range)),
+        alt((line_ending, eof)),
+    )(input)?;
+
+    Ok((
+        input,
+        Section {
+            header,
+            from,
+            to,
+            lines,
+        },
+    ))
+}
+
+fn parse_range(input: &str) -> IResult<&str, (u64, u64, u64)> {
+    map(
+        tuple((complete::u64, space1, complete::u64, space1, complete::u64)),
+        |(destination, _, source, _, length)| (destination, source, length),
+    )(input)
+}
+
+/// Parses the seeds and the maps of an almanac, failing on overlapping or overflowing ranges.
+pub fn parse_input(input: &str) -> Result<Almanac> {
+    let (seeds, sections) = finish(input, parse(input))?;
+    let maps = sections
+        .into_iter()
+        .map(|section| section.into_map(input))
+        .collect::<Result<Vec<CategoryMap>>>()?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_seed_to_location() -> Result<()> {
+        let almanac = parse_input(TESTDATA)?;
+        let seed_to_soil = almanac.conversion("seed", "soil")?;
+        let seed_to_location = almanac.conversion("seed", "location")?;
+
+        assert_eq!(81, seed_to_soil.get(79));
+        assert_eq!(14, seed_to_soil.get(14));
+        assert_eq!(57, seed_to_soil.get(55));
+        assert_eq!(13, seed_to_soil.get(13));
+
+        assert_eq!(82, seed_to_location.get(79));
+        assert_eq!(43, seed_to_location.get(14));
+        assert_eq!(86, seed_to_location.get(55));
+        assert_eq!(35, seed_to_location.get(13));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_range_to_locations() -> Result<()> {
+        let almanac = parse_input(TESTDATA)?;
+        let seed_to_soil = almanac.conversion("seed", "soil")?;
+
+        assert_eq!(vec![57..71], seed_to_soil.get_range(55..69));
+        assert_eq!(vec![10..20], seed_to_soil.get_range(10..20));
+        assert_eq!(
+            vec![45..50, 52..100, 50..52],
+            seed_to_soil.get_range(45..100)
+        );
+
+        let seed_to_location = almanac.conversion("seed", "location")?;
+        assert_eq!(vec![46..47], seed_to_location.get_range(82..83));
+
+        assert_eq!(vec![79..93, 55..68], almanac.seed_ranges()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_seed_ranges() -> Result<()> {
+        let almanac = parse_input("seeds: 1 2 3\n\nseed-to-location map:\n0 1 1\n")?;
+        assert_eq!(0, part_one(&almanac)?);
+        assert!(part_two(&almanac).is_err());
+
+        let almanac =
+            parse_input("seeds: 18446744073709551615 2\n\nseed-to-location map:\n0 1 1\n")?;
+        assert!(part_two(&almanac).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_to_location_map() -> Result<()> {
+        let almanac = parse_input(TESTDATA)?;
+        let seed_to_location = almanac.conversion("seed", "location")?;
+        let composed = seed_to_location.compose();
+
+        for seed in 0..120 {
+            assert_eq!(seed_to_location.get(seed), composed.get(seed));
+        }
+        assert!(composed.breakpoints().windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(Some(46), composed.min_over(79..93));
+        assert_eq!(Some(56), composed.min_over(55..68));
+        assert_eq!(None, composed.min_over(10..10));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_inverse() -> Result<()> {
+        let almanac = parse_input(TESTDATA)?;
+        let seed_to_soil = almanac.conversion("seed", "soil")?;
+
+        assert_eq!(vec![10..20], seed_to_soil.inverse_range(10..20));
+        assert_eq!(vec![98..100], seed_to_soil.inverse_range(50..52));
+        assert_eq!(vec![48..50, 98..100], seed_to_soil.inverse_range(48..52));
+        assert!(seed_to_soil.inverse_range(5..5).is_empty());
+
+        let seed_to_location = almanac.conversion("seed", "location")?;
+        for seed in 0..120 {
+            let location = seed_to_location.get(seed);
+            assert!(seed_to_location
+                .inverse_range(location..(location + 1))
+                .iter()
+                .any(|range| range.contains(&seed)));
+        }
+        assert!(almanac
+            .inverse("seed", "location", 46..47)?
+            .iter()
+            .any(|range| range.contains(&82)));
+        assert!(almanac.inverse("location", "seed", 0..10).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_ranges() {
+        let overlapping = "seeds: 1\n\nseed-to-soil map:\n0 10 5\n20 12 5\n";
+        let error = parse_input(overlapping).unwrap_err().to_string();
+        assert!(error.starts_with("Source ranges 10..15 and 12..17 overlap at line 3"));
+
+        let overflowing = "seeds: 1\n\nseed-to-soil map:\n0 10 5\n0 18446744073709551615 2\n";
+        let error = parse_input(overflowing).unwrap_err().to_string();
+        assert!(error.contains("overflows at line 5"));
+    }
+
+    #[test]
+    fn test_segments() -> Result<()> {
+        let map = AlmanacMap::new(vec![
+            AlmanacRange::new(50, 98, 2)?,
+            AlmanacRange::new(52, 50, 48)?,
+        ])?;
+
+        assert_eq!(vec![0, 50, 98, 100], map.breakpoints());
+        assert_eq!(2, map.ranges().count());
+        assert_eq!(49, map.get(49));
+        assert_eq!(52, map.get(50));
+        assert_eq!(51, map.get(99));
+        assert_eq!(u64::MAX, map.get(u64::MAX));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conversion() -> Result<()> {
+        let almanac = parse_input(TESTDATA)?;
+
+        assert_eq!(5, almanac.conversion("soil", "humidity")?.maps.len());
+        assert_eq!(0, almanac.conversion("water", "water")?.maps.len());
+        assert!(almanac.conversion("location", "seed").is_err());
+        assert!(almanac.conversion("seed", "weather").is_err());
+
+        let almanac = parse_input(
+            "seeds: 1 2\n\nsoil-to-water map:\n0 1 1\n\nseed-to-soil map:\n5 1 1\n\nsoil-to-color map:\n7 5 1\n",
+        )?;
+        assert_eq!(vec!["soil", "water", "seed", "color"], almanac.categories());
+        assert_eq!(7, almanac.conversion("seed", "color")?.get(1));
+        assert_eq!(5, almanac.conversion("seed", "water")?.get(1));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,439 @@
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Result};
+use aoc_core::{finish, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, line_ending, space1},
+    combinator::eof,
+    multi::separated_list1,
+    sequence::{delimited, pair},
+    IResult,
+};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<Race>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input, Kerning::Columns)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+/// The product of the number of ways to win each race.
+pub fn part_one(input: &[Race]) -> Result<u128> {
+    ways_to_win(input)
+}
+
+/// The number of ways to win the single race the sheet describes once its bad kerning is ignored.
+pub fn part_two(input: &[Race]) -> Result<u128> {
+    Ok(solve_race(&Race::merge(input)?))
+}
+
+/// The product of the number of ways to win each race.
+pub fn ways_to_win(input: &[Race]) -> Result<u128> {
+    match input
+        .iter()
+        .try_fold(1u128, |product, race| product.checked_mul(solve_race(race)))
+    {
+        Some(product) => Ok(product),
+        None => bail!("The product of the ways to win does not fit in a u128"),
+    }
+}
+
+/// The best hold time and distance of `model` for each of the races.
+pub fn best_runs(races: &[Race], model: &BoatModel) -> Vec<BestRun> {
+    races.iter().map(|race| model.best_run(race)).collect()
+}
+
+/// The number of button hold times that beat the race's record distance with the toy boat.
+pub fn solve_race(race: &Race) -> u128 {
+    BoatModel::default().ways_to_win(race)
+}
+
+/// The first number in `lo..hi` for which `predicate` holds, or `hi` when there is none. The predicate must be false
+/// up to some number and true from there on.
+fn first_where(mut lo: u128, mut hi: u128, predicate: impl Fn(u128) -> bool) -> u128 {
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if predicate(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    lo
+}
+
+/// How the boat's speed depends on how long its button is held: it starts at `initial_speed` and gains
+/// `acceleration` for every millisecond the button is held, up to `max_speed`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoatModel {
+    initial_speed: u128,
+    acceleration: u128,
+    max_speed: Option<u128>,
+}
+
+/// The best a boat can do in a race.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BestRun {
+    pub hold: u128,
+    pub distance: u128,
+}
+
+impl Display for BestRun {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "hold {} ms to travel {} mm", self.hold, self.distance)
+    }
+}
+
+impl Default for BoatModel {
+    /// The toy boat of the puzzle, which starts standing still and speeds up by one millimeter per millisecond.
+    fn default() -> Self {
+        BoatModel::new(0, 1, None)
+    }
+}
+
+impl BoatModel {
+    pub fn new(initial_speed: u128, acceleration: u128, max_speed: Option<u128>) -> Self {
+        BoatModel {
+            initial_speed,
+            acceleration,
+            max_speed,
+        }
+    }
+
+    /// The speed after holding the button for `hold` milliseconds.
+    pub fn speed(&self, hold: u128) -> u128 {
+        let speed = self
+            .acceleration
+            .saturating_mul(hold)
+            .saturating_add(self.initial_speed);
+        self.max_speed
+            .map_or(speed, |max_speed| speed.min(max_speed))
+    }
+
+    /// The distance travelled in a race of `duration` milliseconds when holding the button for `hold` of them,
+    /// saturating at `u128::MAX`.
+    pub fn distance(&self, duration: u128, hold: u128) -> u128 {
+        duration
+            .checked_sub(hold)
+            .map_or(0, |moving| self.speed(hold).saturating_mul(moving))
+    }
+
+    /// The shortest hold time that travels the furthest in the race, and that distance.
+    ///
+    /// The distance first grows as a concave quadratic while the boat speeds up and then shrinks once it has reached
+    /// its maximum speed, so the best hold time is the first one that does not improve on the next.
+    pub fn best_run(&self, race: &Race) -> BestRun {
+        let hold = first_where(0, race.duration, |hold| {
+            self.distance(race.duration, hold) >= self.distance(race.duration, hold + 1)
+        });
+
+        BestRun {
+            hold,
+            distance: self.distance(race.duration, hold),
+        }
+    }
+
+    /// The shortest and longest hold times that beat the record, or `None` when it can't be beaten.
+    pub fn winning_hold_times(&self, race: &Race) -> Option<RangeInclusive<u128>> {
+        if *self == BoatModel::default() {
+            return race.winning_hold_times();
+        }
+
+        let best = self.best_run(race);
+        if best.distance <= race.distance {
+            return None;
+        }
+        let wins = |hold| self.distance(race.duration, hold) > race.distance;
+        let shortest = first_where(0, best.hold, wins);
+        let longest = if wins(race.duration) {
+            race.duration
+        } else {
+            first_where(best.hold, race.duration, |hold| !wins(hold)) - 1
+        };
+
+        Some(shortest..=longest)
+    }
+
+    /// The number of hold times that beat the record.
+    pub fn ways_to_win(&self, race: &Race) -> u128 {
+        self.winning_hold_times(race)
+            .map_or(0, |holds| holds.end() - holds.start() + 1)
+    }
+}
+
+/// How the numbers on the sheet of paper are read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kerning {
+    /// Every column is a separate race.
+    Columns,
+    /// The spaces between the numbers are bad kerning, so the sheet describes a single race.
+    Merged,
+}
+
+/// A race with its duration and the record distance to beat.
+#[derive(Debug)]
+pub struct Race {
+    duration: u128,
+    distance: u128,
+}
+
+impl Race {
+    pub fn new(duration: u128, distance: u128) -> Self {
+        Race { duration, distance }
+    }
+
+    pub fn duration(&self) -> u128 {
+        self.duration
+    }
+
+    pub fn distance(&self) -> u128 {
+        self.distance
+    }
+
+    /// Whether holding the button for `hold` milliseconds beats the record.
+    pub fn wins(&self, hold: u128) -> bool {
+        hold <= self.duration
+            && hold
+                .checked_mul(self.duration - hold)
+                .is_none_or(|distance| distance > self.distance)
+    }
+
+    /// The shortest and longest hold times that beat the record, or `None` when it can't be beaten.
+    ///
+    /// Holding for `h` beats the record `d` when `h * (t - h) > d`. The distance is symmetric around `m = t / 2`,
+    /// where it is largest, and writing `h = m - k` turns the condition into `k * k < m * (t - m) - d` for an even
+    /// duration and `k * (k + 1) < m * (t - m) - d` for an odd one, which the integer square root solves exactly.
+    pub fn winning_hold_times(&self) -> Option<RangeInclusive<u128>> {
+        let half = self.duration / 2;
+        if !self.wins(half) {
+            return None;
+        }
+
+        let shortest = match half.checked_mul(self.duration - half) {
+            Some(longest_distance) => {
+                let slack = longest_distance - self.distance - 1;
+                let mut k = slack.isqrt();
+                if self.duration % 2 == 1 && k * (k + 1) > slack {
+                    k -= 1;
+                }
+                half - k
+            }
+            None => first_where(0, half, |hold| self.wins(hold)),
+        };
+
+        Some(shortest..=self.duration - shortest)
+    }
+
+    /// Concatenates the digits of all races into a single race, failing when it does not fit in a `u128`.
+    pub fn merge(races: &[Race]) -> Result<Self> {
+        let duration = concat_digits(races.iter().map(|race| race.duration));
+        let distance = concat_digits(races.iter().map(|race| race.distance));
+        match (duration, distance) {
+            (Some(duration), Some(distance)) => Ok(Race { duration, distance }),
+            _ => bail!("The merged race does not fit in a u128"),
+        }
+    }
+}
+
+/// Appends the digits of the numbers to each other, or `None` on overflow.
+fn concat_digits(mut numbers: impl Iterator<Item = u128>) -> Option<u128> {
+    numbers.try_fold(0, |concatenated: u128, number| {
+        if concatenated == 0 {
+            return Some(number);
+        }
+        let shift = 10u128.checked_pow(number.checked_ilog10().unwrap_or(0) + 1)?;
+        concatenated.checked_mul(shift)?.checked_add(number)
+    })
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Race>> {
+    let (input, durations) = parse_line(input, "Time:")?;
+    let (input, distances) = parse_line(input, "Distance:")?;
+
+    let races = durations
+        .iter()
+        .zip(distances)
+        .map(|(duration, distance)| Race {
+            duration: *duration,
+            distance,
+        })
+        .collect();
+
+    Ok((input, races))
+}
+
+fn parse_line<'a>(input: &'a str, label: &str) -> IResult<&'a str, Vec<u128>> {
+    delimited(
+        pair(tag(label), space1),
+        separated_list1(space1, complete::u128),
+        alt((line_ending, eof)),
+    )(input)
+}
+
+/// Parses the times and distances into races, merging them into one race when the sheet has bad kerning.
+pub fn parse_input(input: &str, kerning: Kerning) -> Result<Vec<Race>> {
+    let races = finish(input, parse(input))?;
+
+    match kerning {
+        Kerning::Columns => Ok(races),
+        Kerning::Merged => Ok(vec![Race::merge(&races)?]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_winning_hold_times() {
+        assert_eq!(Some(2..=5), Race::new(7, 9).winning_hold_times());
+        assert_eq!(Some(4..=11), Race::new(15, 40).winning_hold_times());
+        assert_eq!(Some(11..=19), Race::new(30, 200).winning_hold_times());
+        assert_eq!(71503, solve_race(&Race::new(71530, 940_200)));
+
+        assert_eq!(None, Race::new(0, 0).winning_hold_times());
+        assert_eq!(None, Race::new(1, 0).winning_hold_times());
+        assert_eq!(None, Race::new(4, 4).winning_hold_times());
+        assert_eq!(
+            Some(1..=u128::MAX - 1),
+            Race::new(u128::MAX, 0).winning_hold_times()
+        );
+    }
+
+    #[test]
+    fn test_ways_to_win() -> Result<()> {
+        let races = parse_input(include_str!("test.txt"), Kerning::Columns)?;
+        assert_eq!(288, part_one(&races)?);
+
+        let longest = [Race::new(u128::MAX, 0), Race::new(u128::MAX, 0)];
+        assert!(ways_to_win(&longest).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge() -> Result<()> {
+        let races = parse_input(include_str!("test.txt"), Kerning::Merged)?;
+        assert_eq!(1, races.len());
+        assert_eq!((71530, 940_200), (races[0].duration(), races[0].distance()));
+
+        let merged = Race::merge(&[Race::new(0, 12), Race::new(5, 0), Race::new(0, 3)])?;
+        assert_eq!((50, 1203), (merged.duration(), merged.distance()));
+
+        assert!(Race::merge(&[Race::new(u128::MAX, 1)]).is_ok());
+        assert!(Race::merge(&[Race::new(u128::MAX, 1), Race::new(1, 1)]).is_err());
+        assert!(Race::merge(&[Race::new(u128::MAX / 10 + 1, 1), Race::new(0, 1)]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_boat_model() {
+        let race = Race::new(7, 9);
+        assert_eq!(
+            BestRun {
+                hold: 3,
+                distance: 12
+            },
+            BoatModel::default().best_run(&race)
+        );
+
+        let model = BoatModel::new(1, 2, Some(6));
+        assert_eq!(
+            vec![1, 3, 5, 6, 6],
+            (0..5).map(|hold| model.speed(hold)).collect::<Vec<u128>>()
+        );
+        assert_eq!(
+            BestRun {
+                hold: 2,
+                distance: 25
+            },
+            model.best_run(&race)
+        );
+        assert_eq!(Some(1..=5), model.winning_hold_times(&race));
+        assert_eq!(None, model.winning_hold_times(&Race::new(7, 25)));
+
+        let longest = Race::new(u128::MAX, 0);
+        let unbounded = BoatModel::new(1, 1, None);
+        assert_eq!(
+            Some(0..=u128::MAX - 1),
+            unbounded.winning_hold_times(&longest)
+        );
+        assert_eq!(u128::MAX, unbounded.ways_to_win(&longest));
+
+        let runs = best_runs(&[race, Race::new(3, 0)], &model);
+        assert_eq!(
+            vec!["hold 2 ms to travel 25 mm", "hold 1 ms to travel 6 mm"],
+            runs.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn test_boat_model_brute_force() {
+        let models = [
+            BoatModel::new(0, 1, None),
+            BoatModel::new(3, 1, None),
+            BoatModel::new(0, 3, None),
+            BoatModel::new(2, 2, Some(9)),
+            BoatModel::new(5, 0, None),
+            BoatModel::new(0, 4, Some(4)),
+        ];
+        for model in models {
+            for duration in 0..30 {
+                let best = (0..=duration)
+                    .map(|hold| model.distance(duration, hold))
+                    .max()
+                    .unwrap();
+                assert_eq!(best, model.best_run(&Race::new(duration, 0)).distance);
+                for distance in 0..=best + 1 {
+                    let race = Race::new(duration, distance);
+                    let count = (0..=duration)
+                        .filter(|hold| model.distance(duration, *hold) > distance)
+                        .count() as u128;
+                    assert_eq!(
+                        count,
+                        model.ways_to_win(&race),
+                        "{model:?} {duration} {distance}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_winning_hold_times_brute_force() {
+        for duration in 0..60 {
+            for distance in 0..(duration * duration / 4 + 2) {
+                let race = Race::new(duration, distance);
+                let count = (0..=duration).filter(|hold| race.wins(*hold)).count() as u128;
+                assert_eq!(count, solve_race(&race), "{duration} {distance}");
+            }
+        }
+    }
+}
@@ -0,0 +1,695 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::Result;
+use aoc_core::{finish, Solution};
+use itertools::Itertools;
+use nom::{
+    character::complete::{self, anychar, line_ending, space1},
+    combinator::{map, map_res},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<Hand>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_two(input))
+    }
+}
+
+/// The total winnings of the hands ordered by strength.
+pub fn part_one(input: &[Hand]) -> u64 {
+    total_winnings(input, &Rules::standard())
+}
+
+/// The total winnings with jacks played as jokers.
+pub fn part_two(input: &[Hand]) -> u64 {
+    total_winnings(input, &Rules::jokers())
+}
+
+/// The sum of every hand's bid multiplied by its position when the hands are ordered by strength under `rules`.
+pub fn total_winnings(hands: &[Hand], rules: &Rules) -> u64 {
+    RankedHands::new(hands, rules).total_winnings()
+}
+
+/// Hands ordered by strength under one set of rules. The packed key of every hand is computed once, when the hands
+/// are ranked, and the ordering is shared by the winnings and the placements.
+#[derive(Clone, Debug)]
+pub struct RankedHands<'a> {
+    hands: &'a [Hand],
+    rules: &'a Rules,
+    keys: Option<Vec<u128>>,
+    order: Vec<usize>,
+}
+
+impl<'a> RankedHands<'a> {
+    /// Ranks the hands under `rules`. Equal hands keep their order in the input. When a hand is too long for a
+    /// packed key, all hands are compared by [`Hand::sort_key`] instead, so hands of different lengths still
+    /// order consistently.
+    pub fn new(hands: &'a [Hand], rules: &'a Rules) -> Self {
+        let keys = hands
+            .iter()
+            .map(|hand| hand.key(rules))
+            .collect::<Option<Vec<u128>>>();
+        let indices = 0..hands.len();
+        let order = match &keys {
+            Some(keys) => indices.sorted_by_key(|index| keys[*index]).collect(),
+            None => indices
+                .sorted_by_cached_key(|index| hands[*index].sort_key(rules))
+                .collect(),
+        };
+
+        RankedHands {
+            hands,
+            rules,
+            keys,
+            order,
+        }
+    }
+
+    pub fn hands(&self) -> &'a [Hand] {
+        self.hands
+    }
+
+    pub fn rules(&self) -> &'a Rules {
+        self.rules
+    }
+
+    /// The packed key of the hand at `index` in the input, or `None` when the hands are too long for packed keys.
+    pub fn key(&self, index: usize) -> Option<u128> {
+        self.keys.as_ref().map(|keys| keys[index])
+    }
+
+    /// The indices of the hands in the input, from the weakest to the strongest hand.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// The position of every hand, by its index in the input.
+    pub fn positions(&self) -> Vec<u64> {
+        let mut positions = vec![0; self.hands.len()];
+        for (i, index) in self.order.iter().enumerate() {
+            positions[*index] = i as u64 + 1;
+        }
+
+        positions
+    }
+
+    pub fn total_winnings(&self) -> u64 {
+        self.order
+            .iter()
+            .enumerate()
+            .map(|(i, index)| (i + 1) as u64 * u64::from(self.hands[*index].score))
+            .sum()
+    }
+
+    /// Explains [`RankedHands::total_winnings`]: the placement of every hand, from the weakest to the strongest.
+    pub fn placements(&self) -> Vec<Placement> {
+        self.order
+            .iter()
+            .enumerate()
+            .map(|(i, index)| self.placement(*index, i as u64 + 1))
+            .collect()
+    }
+
+    fn placement(&self, index: usize, position: u64) -> Placement {
+        let hand = &self.hands[index];
+        Placement {
+            hand: hand.clone(),
+            rank: hand.rank(self.rules),
+            wild_as: self.rules.wild_as(&hand.cards),
+            position,
+            winnings: position * u64::from(hand.score),
+        }
+    }
+}
+
+/// Where a hand ends up when the hands are ordered under some rules, and why.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Placement {
+    pub hand: Hand,
+    pub rank: Rank,
+    /// The label the wild cards in the hand stand for, if it has any.
+    pub wild_as: Option<Label>,
+    /// The position in the ordering, starting at 1 for the weakest hand.
+    pub position: u64,
+    /// The bid multiplied by the position.
+    pub winnings: u64,
+}
+
+impl Display for Placement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>5}  {}  {}", self.position, self.hand, self.rank)?;
+        if let Some(label) = self.wild_as {
+            write!(f, ", wild as {label}")?;
+        }
+        write!(
+            f,
+            ": {} * {} = {}",
+            self.hand.score, self.position, self.winnings
+        )
+    }
+}
+
+/// A hand that is placed differently under two sets of rules.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub before: Placement,
+    pub after: Placement,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}  {:>5} -> {:<5}  {} -> {}",
+            self.after.hand,
+            self.before.position,
+            self.after.position,
+            self.before.rank,
+            self.after.rank
+        )
+    }
+}
+
+/// Explains [`total_winnings`]: the placement of every hand under `rules`, from the weakest to the strongest.
+pub fn placements(hands: &[Hand], rules: &Rules) -> Vec<Placement> {
+    RankedHands::new(hands, rules).placements()
+}
+
+/// The hands that are placed differently in `after` than in `before`, ordered by their placement in `after`. Both
+/// have to rank the same hands.
+pub fn moves(before: &RankedHands, after: &RankedHands) -> Vec<Move> {
+    let positions = before.positions();
+
+    after
+        .order
+        .iter()
+        .enumerate()
+        .filter(|(i, index)| positions[**index] != *i as u64 + 1)
+        .map(|(i, index)| Move {
+            before: before.placement(*index, positions[*index]),
+            after: after.placement(*index, i as u64 + 1),
+        })
+        .collect()
+}
+
+/// A hand of cards and its bid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<Label>,
+    score: u32,
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cards.iter().join(""))
+    }
+}
+
+impl Hand {
+    pub fn new(cards: Vec<Label>, score: u32) -> Self {
+        Self { cards, score }
+    }
+
+    pub fn cards(&self) -> &[Label] {
+        &self.cards
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    pub fn rank(&self, rules: &Rules) -> Rank {
+        rules.rank(&self.cards)
+    }
+
+    /// Orders hands under `rules` like [`Hand::sort_key`], packed into an integer. `None` for hands of more than
+    /// [`MAX_HAND_SIZE`] cards.
+    pub fn key(&self, rules: &Rules) -> Option<u128> {
+        rules.key(&self.cards)
+    }
+
+    /// Orders hands under `rules`: by rank first, and then by the strength of each card in turn.
+    pub fn sort_key(&self, rules: &Rules) -> (Rank, Vec<u8>) {
+        let strengths = self
+            .cards
+            .iter()
+            .map(|label| rules.strength(*label))
+            .collect();
+
+        (self.rank(rules), strengths)
+    }
+}
+
+/// A variant of Camel Cards: which labels are wild, and how wild cards compare when breaking ties.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    wild: u16,
+    wild_order: WildOrder,
+}
+
+/// How wild cards compare to other cards when breaking ties between hands of the same rank.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WildOrder {
+    /// Weaker than any other card.
+    Lowest,
+    /// As strong as the label on the card.
+    Natural,
+    /// Stronger than any other card.
+    Highest,
+}
+
+impl Rules {
+    pub fn new(wild: Vec<Label>, wild_order: WildOrder) -> Self {
+        Rules {
+            wild: wild.iter().fold(0, |mask, label| mask | 1 << *label as u16),
+            wild_order,
+        }
+    }
+
+    /// The rules without any wild cards.
+    pub fn standard() -> Self {
+        Rules::new(vec![], WildOrder::Natural)
+    }
+
+    /// Jacks are jokers: they complete the best possible hand, but are the weakest card when breaking ties.
+    pub fn jokers() -> Self {
+        Rules::new(vec![Label::Jack], WildOrder::Lowest)
+    }
+
+    pub fn is_wild(&self, label: Label) -> bool {
+        self.wild & 1 << label as u16 != 0
+    }
+
+    /// The strength of `label` when breaking ties, with 0 the weakest.
+    pub fn strength(&self, label: Label) -> u8 {
+        let natural = label as u8 + 1;
+        match self.wild_order {
+            WildOrder::Lowest if self.is_wild(label) => 0,
+            WildOrder::Highest if self.is_wild(label) => Label::Ace as u8 + 2,
+            _ => natural,
+        }
+    }
+
+    /// The sizes of the groups of equal labels, largest first, with the wild cards joining the largest group.
+    pub fn signature(&self, cards: &[Label]) -> Vec<usize> {
+        self.group_sizes(cards)
+            .into_iter()
+            .take_while(|size| *size > 0)
+            .collect()
+    }
+
+    /// Packs the rank and the strengths of the cards into one integer that orders hands of at most
+    /// [`MAX_HAND_SIZE`] cards. Every group size and strength takes four bits: the group sizes, largest first, fill
+    /// the high bits and the strengths, left aligned, the low bits. `None` for longer hands.
+    pub fn key(&self, cards: &[Label]) -> Option<u128> {
+        if cards.len() > MAX_HAND_SIZE {
+            return None;
+        }
+        let rank = self
+            .group_sizes(cards)
+            .iter()
+            .fold(0, |key, size| key << 4 | *size as u128);
+        let strengths = cards
+            .iter()
+            .fold(0, |key, label| key << 4 | u128::from(self.strength(*label)));
+
+        Some(rank << (4 * MAX_HAND_SIZE) | strengths << (4 * (MAX_HAND_SIZE - cards.len())))
+    }
+
+    /// The sizes of the groups of equal labels, largest first and padded with zeros.
+    fn group_sizes(&self, cards: &[Label]) -> [usize; LABELS] {
+        let mut sizes = [0; LABELS];
+        let mut wild = 0;
+        for label in cards {
+            if self.is_wild(*label) {
+                wild += 1;
+            } else {
+                sizes[*label as usize] += 1;
+            }
+        }
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes[0] += wild;
+
+        sizes
+    }
+
+    /// The label the wild cards in `cards` stand for: the one in the largest group, the strongest one on a tie.
+    /// When every card is wild they all stand for aces. `None` when there are no wild cards.
+    pub fn wild_as(&self, cards: &[Label]) -> Option<Label> {
+        if !cards.iter().any(|label| self.is_wild(*label)) {
+            return None;
+        }
+
+        let counts = cards.iter().filter(|label| !self.is_wild(**label)).counts();
+        let label = counts
+            .into_iter()
+            .max_by_key(|(label, count)| (*count, self.strength(**label)))
+            .map_or(Label::Ace, |(label, _)| *label);
+
+        Some(label)
+    }
+
+    /// The rank of the best hand the wild cards can form.
+    pub fn rank(&self, cards: &[Label]) -> Rank {
+        Rank(self.signature(cards))
+    }
+}
+
+/// The most cards a hand can have for every group size and strength in [`Rules::key`] to fit in four bits. Longer
+/// hands are compared by [`Hand::sort_key`].
+pub const MAX_HAND_SIZE: usize = 15;
+
+const LABELS: usize = Label::Ace as usize + 1;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Label {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Display for Label {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Label::Two => '2',
+            Label::Three => '3',
+            Label::Four => '4',
+            Label::Five => '5',
+            Label::Six => '6',
+            Label::Seven => '7',
+            Label::Eight => '8',
+            Label::Nine => '9',
+            Label::Ten => 'T',
+            Label::Jack => 'J',
+            Label::Queen => 'Q',
+            Label::King => 'K',
+            Label::Ace => 'A',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+impl TryFrom<char> for Label {
+    type Error = ();
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Label::Two),
+            '3' => Ok(Label::Three),
+            '4' => Ok(Label::Four),
+            '5' => Ok(Label::Five),
+            '6' => Ok(Label::Six),
+            '7' => Ok(Label::Seven),
+            '8' => Ok(Label::Eight),
+            '9' => Ok(Label::Nine),
+            'T' => Ok(Label::Ten),
+            'J' => Ok(Label::Jack),
+            'Q' => Ok(Label::Queen),
+            'K' => Ok(Label::King),
+            'A' => Ok(Label::Ace),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The type of a hand of any size: the sizes of its groups of equal labels, largest first. Ranks compare
+/// lexicographically, so a larger first group wins, then a larger second group, and so on.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rank(Vec<usize>);
+
+impl Rank {
+    pub fn counts(&self) -> &[usize] {
+        &self.0
+    }
+}
+
+impl Display for Rank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0.as_slice() {
+            [5] => f.write_str("five of a kind"),
+            [4, 1] => f.write_str("four of a kind"),
+            [3, 2] => f.write_str("full house"),
+            [3, 1, 1] => f.write_str("three of a kind"),
+            [2, 2, 1] => f.write_str("two pair"),
+            [2, 1, 1, 1] => f.write_str("one pair"),
+            [1, 1, 1, 1, 1] => f.write_str("high card"),
+            counts => write!(f, "{}", counts.iter().join("-")),
+        }
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Hand>> {
+    separated_list1(line_ending, parse_line)(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Hand> {
+    map(
+        separated_pair(many1(parse_label), space1, complete::u32),
+        |(cards, score)| Hand::new(cards, score),
+    )(input)
+}
+
+fn parse_label(input: &str) -> IResult<&str, Label> {
+    map_res(anychar, Label::try_from)(input)
+}
+
+/// Parses one hand and bid per line.
+pub fn parse_input(input: &str) -> Result<Vec<Hand>> {
+    let input = finish(input, parse(input))?;
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(hand: &str) -> Vec<Label> {
+        hand.chars().map(|c| Label::try_from(c).unwrap()).collect()
+    }
+
+    fn rank(rules: &Rules, hand: &str) -> String {
+        rules.rank(&cards(hand)).to_string()
+    }
+
+    #[test]
+    fn test_rank() {
+        let standard = Rules::standard();
+        let jokers = Rules::jokers();
+
+        assert_eq!("one pair", rank(&standard, "32T3K"));
+        assert_eq!("three of a kind", rank(&standard, "T55J5"));
+        assert_eq!("four of a kind", rank(&jokers, "T55J5"));
+        assert_eq!("four of a kind", rank(&jokers, "QQQJA"));
+        assert_eq!("full house", rank(&jokers, "KKJQQ"));
+        assert_eq!("five of a kind", rank(&jokers, "JJJJJ"));
+        assert_eq!(vec![5], jokers.signature(&cards("JJJJJ")));
+    }
+
+    #[test]
+    fn test_hand_sizes() -> Result<()> {
+        let rules = Rules::standard();
+        assert!(rules.rank(&cards("AAK")) > rules.rank(&cards("AKQ")));
+        assert!(rules.rank(&cards("KKK")) > rules.rank(&cards("AAK")));
+        assert!(rules.rank(&cards("2233344")) > rules.rank(&cards("2223456")));
+        assert!(rules.rank(&cards("2222333")) > rules.rank(&cards("2222345")));
+        assert_eq!("4-3", rank(&rules, "2222333"));
+
+        let hands = parse_input("AAK 1\nKKK 10\nAKQ 100")?;
+        assert_eq!(100 + 2 + 30, part_one(&hands));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let rules = Rules::new(vec![Label::Two, Label::Three], WildOrder::Highest);
+
+        assert_eq!("four of a kind", rank(&rules, "23AA4"));
+        assert!(
+            Hand::new(cards("2AAA4"), 0).key(&rules) > Hand::new(cards("AAAA4"), 0).key(&rules)
+        );
+        assert_eq!(
+            Hand::new(cards("3KKK4"), 0).key(&rules),
+            Hand::new(cards("2KKK4"), 0).key(&rules)
+        );
+    }
+
+    #[test]
+    fn test_key() {
+        let hands = [
+            "32T3K", "T55J5", "KK677", "KTJJT", "QQQJA", "JJJJJ", "AAAAA", "2345J", "J2345",
+            "AJJAA", "KKKQQ", "KKKJ2",
+        ];
+        for rules in [
+            Rules::standard(),
+            Rules::jokers(),
+            Rules::new(vec![Label::Two, Label::King], WildOrder::Highest),
+        ] {
+            for a in hands {
+                for b in hands {
+                    let by_rank =
+                        rules
+                            .rank(&cards(a))
+                            .cmp(&rules.rank(&cards(b)))
+                            .then_with(|| {
+                                cards(a)
+                                    .iter()
+                                    .map(|label| rules.strength(*label))
+                                    .cmp(cards(b).iter().map(|label| rules.strength(*label)))
+                            });
+                    assert_eq!(
+                        by_rank,
+                        rules.key(&cards(a)).cmp(&rules.key(&cards(b))),
+                        "{a} {b}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_many_hands() {
+        let count = 200_000;
+        let hands = vec![Hand::new(cards("32T3K"), 1000); count];
+
+        assert_eq!(
+            1000 * count as u64 * (count as u64 + 1) / 2,
+            part_one(&hands)
+        );
+    }
+
+    #[test]
+    fn test_long_hands() -> Result<()> {
+        let long = "23456789TJQKA2345";
+        assert_eq!(None, Rules::standard().key(&cards(long)));
+
+        let hands = parse_input(&format!(
+            "{long} 1\nAAK 10\n2222222222222222222222222222222 100"
+        ))?;
+        assert_eq!(10 + 2 + 300, part_one(&hands));
+        assert_eq!(
+            vec!["AAK", long],
+            placements(&hands[..2], &Rules::standard())
+                .iter()
+                .map(|placement| placement.hand.to_string())
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_placements() -> Result<()> {
+        let hands = parse_input(include_str!("test.txt"))?;
+        let placements = placements(&hands, &Rules::jokers());
+
+        let explained = placements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "    1  32T3K  one pair: 765 * 1 = 765",
+                "    2  KK677  two pair: 28 * 2 = 56",
+                "    3  T55J5  four of a kind, wild as 5: 684 * 3 = 2052",
+                "    4  QQQJA  four of a kind, wild as Q: 483 * 4 = 1932",
+                "    5  KTJJT  four of a kind, wild as T: 220 * 5 = 1100",
+            ],
+            explained
+        );
+        assert_eq!(
+            part_two(&hands),
+            placements
+                .iter()
+                .map(|placement| placement.winnings)
+                .sum::<u64>()
+        );
+        assert_eq!(Some(Label::Ace), Rules::jokers().wild_as(&cards("JJJJJ")));
+        assert_eq!(Some(Label::King), Rules::jokers().wild_as(&cards("QQKKJ")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_moves() -> Result<()> {
+        let hands = parse_input(include_str!("test.txt"))?;
+        let (standard, jokers) = (Rules::standard(), Rules::jokers());
+        let moves = moves(
+            &RankedHands::new(&hands, &standard),
+            &RankedHands::new(&hands, &jokers),
+        );
+
+        let moved = moves.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "KK677      3 -> 2      two pair -> two pair",
+                "T55J5      4 -> 3      three of a kind -> four of a kind",
+                "QQQJA      5 -> 4      three of a kind -> four of a kind",
+                "KTJJT      2 -> 5      two pair -> four of a kind",
+            ],
+            moved
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ranked_hands() -> Result<()> {
+        let hands = parse_input(include_str!("test.txt"))?;
+        let rules = Rules::jokers();
+        let ranked = RankedHands::new(&hands, &rules);
+
+        assert_eq!(&[0, 2, 1, 4, 3], ranked.order());
+        assert_eq!(vec![1, 3, 2, 5, 4], ranked.positions());
+        assert_eq!(hands[3].key(&rules), ranked.key(3));
+        assert_eq!(5905, ranked.total_winnings());
+        assert_eq!(
+            ranked.total_winnings(),
+            ranked
+                .placements()
+                .iter()
+                .map(|placement| placement.winnings)
+                .sum::<u64>()
+        );
+
+        let long = vec![Hand::new(vec![Label::Ace; MAX_HAND_SIZE + 1], 1)];
+        assert_eq!(None, RankedHands::new(&long, &rules).key(0));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,533 @@
+// use std::collections::HashMap;
+use ahash::{HashMap, HashMapExt};
+
+use anyhow::{bail, Context, Result};
+use aoc_core::{finish, ParseError, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alphanumeric1, line_ending},
+    combinator::{map, value},
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair, separated_pair},
+    IResult,
+};
+use num::Integer;
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        part_one(&input.directions, &input.network)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        part_two(&input.directions, &input.network)
+    }
+}
+
+/// The number of steps from `AAA` to `ZZZ`.
+pub fn part_one(directions: &[Direction], network: &Network) -> Result<u64> {
+    let start = network.id("AAA").context("There is no node AAA")?;
+    counting_steps(directions, network, start, |location| location == "ZZZ")
+        .context("ZZZ can not be reached from AAA")
+}
+
+/// The number of steps until every node ending in `A` has simultaneously reached a node ending in `Z`.
+pub fn part_two(directions: &[Direction], network: &Network) -> Result<u64> {
+    let cycles = network
+        .ids()
+        .filter(|id| network.name(*id).ends_with('A'))
+        .map(|id| Cycle::find(directions, network, id, |loc| loc.ends_with('Z')))
+        .collect::<Option<Vec<Cycle>>>()
+        .context("There are no directions to follow")?;
+
+    first_common_step(&cycles)
+}
+
+/// Where a walk through the network is on a target. Following the directions from a node eventually repeats a
+/// state, a node at the same position in the directions, and from then on the walk goes around the same cycle
+/// forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    start: u64,
+    length: u64,
+    tail_hits: Vec<u64>,
+    hits: Vec<u64>,
+}
+
+impl Cycle {
+    /// Follows the directions from `start` until a state repeats, recording the steps at which `check_fn`
+    /// accepts the node. `None` when there are no directions, or `start` is not a node of the network.
+    ///
+    /// The walk is only compared with itself at the start of the directions, which finds the same cycle while
+    /// remembering one step per node rather than one per node and direction.
+    pub fn find(
+        directions: &[Direction],
+        network: &Network,
+        start: u32,
+        check_fn: fn(&str) -> bool,
+    ) -> Option<Self> {
+        if start as usize >= network.len() {
+            return None;
+        }
+        let targets = network.targets(check_fn);
+        let mut seen = vec![None; network.len()];
+        let mut hits = vec![];
+        let mut location = start;
+        for (step, (index, direction)) in directions.iter().enumerate().cycle().enumerate() {
+            let step = step as u64;
+            if index == 0 {
+                if let Some(start) = seen[location as usize].replace(step) {
+                    let cycle_hits = hits.split_off(hits.partition_point(|hit| *hit < start));
+                    return Some(Cycle {
+                        start,
+                        length: step - start,
+                        tail_hits: hits,
+                        hits: cycle_hits,
+                    });
+                }
+            }
+
+            // Only steps that have been taken count, so the start location is never a hit.
+            if step > 0 && targets[location as usize] {
+                hits.push(step);
+            }
+            location = network.next(location, direction);
+        }
+
+        None
+    }
+
+    /// The number of steps before the walk goes around its cycle, a multiple of the number of directions.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// The number of steps around the cycle.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// The steps before the cycle at which the walk is on a target.
+    pub fn tail_hits(&self) -> &[u64] {
+        &self.tail_hits
+    }
+
+    /// The steps during the first time around the cycle at which the walk is on a target. It is on a target again
+    /// every [`Cycle::length`] steps after each of them.
+    pub fn hits(&self) -> &[u64] {
+        &self.hits
+    }
+
+    pub fn is_on_target(&self, step: u64) -> bool {
+        if step < self.start {
+            self.tail_hits.contains(&step)
+        } else {
+            let offset = (step - self.start) % self.length;
+            self.hits.contains(&(self.start + offset))
+        }
+    }
+}
+
+/// The first step, at least one, at which every walk is on a target at the same time.
+///
+/// A step before every walk has entered its cycle has to be a hit in the longest tail, so those are checked one by
+/// one. After that each walk is on a target on a set of residues modulo its cycle length, and the residues of
+/// the walks are combined with the Chinese remainder theorem for moduli that need not be coprime.
+pub fn first_common_step(cycles: &[Cycle]) -> Result<u64> {
+    let Some(longest_tail) = cycles.iter().max_by_key(|cycle| cycle.start) else {
+        bail!("There are no walks to synchronise");
+    };
+
+    if let Some(step) = longest_tail
+        .tail_hits
+        .iter()
+        .find(|step| cycles.iter().all(|cycle| cycle.is_on_target(**step)))
+    {
+        return Ok(*step);
+    }
+
+    let mut residues = vec![(0, 1)];
+    for cycle in cycles {
+        let mut combined = vec![];
+        for (residue, modulus) in residues {
+            for hit in &cycle.hits {
+                if let Some(solution) = crt((residue, modulus), (hit % cycle.length, cycle.length))?
+                {
+                    combined.push(solution);
+                }
+            }
+        }
+        combined.sort_unstable();
+        combined.dedup();
+        residues = combined;
+    }
+
+    let earliest = longest_tail.start.max(1);
+    let steps = residues
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let behind = earliest.saturating_sub(residue).div_ceil(modulus);
+            behind
+                .checked_mul(modulus)
+                .and_then(|offset| offset.checked_add(residue))
+                .context("The first common step does not fit in 64 bits")
+        })
+        .collect::<Result<Vec<u64>>>()?;
+
+    steps
+        .into_iter()
+        .min()
+        .context("The walks are never on a target at the same time")
+}
+
+/// Solves `x = a mod m` and `x = b mod n` for moduli that need not be coprime, as `x = c mod lcm(m, n)`, or `None`
+/// when there is no solution.
+fn crt((a, m): (u64, u64), (b, n): (u64, u64)) -> Result<Option<(u64, u64)>> {
+    let (m, n, a, b) = (i128::from(m), i128::from(n), i128::from(a), i128::from(b));
+    let gcd = m.extended_gcd(&n);
+    if (b - a) % gcd.gcd != 0 {
+        return Ok(None);
+    }
+
+    let lcm = (m / gcd.gcd).checked_mul(n);
+    let Some(modulus) = lcm.and_then(|lcm| u64::try_from(lcm).ok()) else {
+        bail!("The combined cycle length does not fit in 64 bits");
+    };
+
+    // m * x = g mod n, so a + m * x * (b - a) / g solves both. Both factors are reduced below n / g, so their
+    // product fits in a u128 and m * k stays below the lcm.
+    let step = n / gcd.gcd;
+    let k = ((b - a) / gcd.gcd).rem_euclid(step) as u128 * gcd.x.rem_euclid(step) as u128
+        % step as u128;
+    let residue = (a + m * k as i128).rem_euclid(i128::from(modulus));
+
+    Ok(Some((residue as u64, modulus)))
+}
+
+/// Follows the directions, repeating them as needed, from `start` until `check_fn` accepts a node, and returns
+/// the number of steps taken.
+///
+/// `None` when no node is ever accepted, which shows as the walk being on the same node at the start of the
+/// directions twice, when there are no directions, or when `start` is not a node of the network.
+pub fn counting_steps(
+    directions: &[Direction],
+    network: &Network,
+    start: u32,
+    check_fn: fn(&str) -> bool,
+) -> Option<u64> {
+    if start as usize >= network.len() {
+        return None;
+    }
+    let targets = network.targets(check_fn);
+    let mut seen = vec![false; network.len()];
+    let mut location = start;
+    for (step, (index, direction)) in directions.iter().enumerate().cycle().enumerate() {
+        if index == 0 && std::mem::replace(&mut seen[location as usize], true) {
+            return None;
+        }
+        location = network.next(location, direction);
+
+        if targets[location as usize] {
+            return Some(step as u64 + 1);
+        }
+    }
+
+    None
+}
+
+/// The left/right instructions and the network of nodes.
+#[derive(Debug)]
+pub struct Input<'a> {
+    directions: Vec<Direction>,
+    network: Network<'a>,
+}
+
+impl<'a> Input<'a> {
+    pub fn directions(&self) -> &[Direction] {
+        &self.directions
+    }
+
+    pub fn network(&self) -> &Network<'a> {
+        &self.network
+    }
+}
+
+/// The nodes of the network, numbered from 0 in the order they are defined, each with the ids of its left and
+/// right neighbours. Walking the network only indexes into a `Vec`; the names are kept to look nodes up and to
+/// print them.
+#[derive(Debug)]
+pub struct Network<'a> {
+    names: Vec<&'a str>,
+    ids: HashMap<&'a str, u32>,
+    neighbours: Vec<[u32; 2]>,
+}
+
+impl<'a> Network<'a> {
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = u32> {
+        0..self.names.len() as u32
+    }
+
+    pub fn id(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: u32) -> &'a str {
+        self.names[id as usize]
+    }
+
+    /// The left and right neighbours of the node `id`.
+    pub fn neighbours(&self, id: u32) -> [u32; 2] {
+        self.neighbours[id as usize]
+    }
+
+    pub fn next(&self, id: u32, direction: &Direction) -> u32 {
+        let [left, right] = self.neighbours(id);
+        match direction {
+            Direction::Left => left,
+            Direction::Right => right,
+        }
+    }
+
+    /// Whether `check_fn` accepts each node, by id.
+    fn targets(&self, check_fn: fn(&str) -> bool) -> Vec<bool> {
+        self.names.iter().map(|name| check_fn(name)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+type Node<'a> = (&'a str, [&'a str; 2]);
+
+fn parse(input: &str) -> IResult<&str, (Vec<Direction>, Vec<Node<'_>>)> {
+    let (input, directions) = parse_directions(input)?;
+    let (input, _) = pair(line_ending, line_ending)(input)?;
+    let (input, lines) = separated_list1(line_ending, parse_line)(input)?;
+
+    Ok((input, (directions, lines)))
+}
+
+fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    many1(alt((parse_left, parse_right)))(input)
+}
+
+fn parse_left(input: &str) -> IResult<&str, Direction> {
+    value(Direction::Left, complete::char('L'))(input)
+}
+
+fn parse_right(input: &str) -> IResult<&str, Direction> {
+    value(Direction::Right, complete::char('R'))(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Node<'_>> {
+    separated_pair(alphanumeric1, tag(" = "), parse_destinations)(input)
+}
+
+fn parse_destinations(input: &str) -> IResult<&str, [&str; 2]> {
+    map(
+        delimited(
+            complete::char('('),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            complete::char(')'),
+        ),
+        |(a, b)| [a, b],
+    )(input)
+}
+
+/// Parses the instructions and the network. Every node has to be defined once, and its neighbours have to be
+/// defined as well.
+pub fn parse_input(input: &str) -> Result<Input<'_>> {
+    let (directions, lines) = finish(input, parse(input))?;
+
+    let mut ids = HashMap::with_capacity(lines.len());
+    for (id, (name, _)) in lines.iter().enumerate() {
+        if ids.insert(*name, id as u32).is_some() {
+            Err(ParseError::at(
+                input,
+                name,
+                format!("Node {name} is defined twice"),
+            ))?;
+        }
+    }
+    let id = |name: &str| {
+        ids.get(name)
+            .copied()
+            .ok_or_else(|| ParseError::at(input, name, format!("Node {name} is not defined")))
+    };
+    let neighbours = lines
+        .iter()
+        .map(|(_, [left, right])| Ok([id(left)?, id(right)?]))
+        .collect::<Result<Vec<[u32; 2]>, ParseError>>()?;
+    let names = lines.into_iter().map(|(name, _)| name).collect();
+
+    Ok(Input {
+        directions,
+        network: Network {
+            names,
+            ids,
+            neighbours,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OFFSET_CYCLES: &str = "L
+
+1A = (1Z, 1Z)
+1Z = (1B, 1B)
+1B = (1C, 1C)
+1C = (1Z, 1Z)
+3A = (3B, 3B)
+3B = (3Z, 3Z)
+3Z = (3C, 3C)
+3C = (3D, 3D)
+3D = (3A, 3A)";
+
+    #[test]
+    fn test_cycle() -> Result<()> {
+        let input = parse_input(OFFSET_CYCLES)?;
+        let start = input.network.id("1A").unwrap();
+        let cycle = Cycle::find(&input.directions, &input.network, start, |loc| {
+            loc.ends_with('Z')
+        })
+        .unwrap();
+
+        assert_eq!((1, 3), (cycle.start(), cycle.length()));
+        assert_eq!((&[][..], &[1][..]), (cycle.tail_hits(), cycle.hits()));
+        assert!(cycle.is_on_target(7));
+        assert!(!cycle.is_on_target(8));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_offset_cycles() -> Result<()> {
+        let input = parse_input(OFFSET_CYCLES)?;
+        assert_eq!(7, part_two(&input.directions, &input.network)?);
+
+        let never = format!("{OFFSET_CYCLES}\n2A = (2B, 2B)\n2B = (2Z, 2Z)\n2Z = (2A, 2A)");
+        let input = parse_input(&never)?;
+        assert!(part_two(&input.directions, &input.network).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tail_hits() -> Result<()> {
+        let input = parse_input(
+            "L\n\n1A = (1Z, 1Z)\n1Z = (1B, 1B)\n1B = (1B, 1B)\n2A = (2Z, 2Z)\n2Z = (2Z, 2Z)",
+        )?;
+        let start = input.network.id("1A").unwrap();
+        let cycle = Cycle::find(&input.directions, &input.network, start, |loc| {
+            loc.ends_with('Z')
+        })
+        .unwrap();
+
+        assert_eq!((&[1][..], &[][..]), (cycle.tail_hits(), cycle.hits()));
+        assert_eq!(1, part_two(&input.directions, &input.network)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unreachable() -> Result<()> {
+        let input = parse_input("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)")?;
+        let start = input.network.id("AAA").unwrap();
+        assert_eq!(
+            None,
+            counting_steps(&input.directions, &input.network, start, |loc| loc == "ZZZ")
+        );
+        assert!(part_one(&input.directions, &input.network).is_err());
+
+        let check_fn: fn(&str) -> bool = |loc| loc == "ZZZ";
+        assert_eq!(None, counting_steps(&[], &input.network, start, check_fn));
+        assert_eq!(None, Cycle::find(&[], &input.network, start, check_fn));
+        assert_eq!(
+            None,
+            counting_steps(&input.directions, &input.network, 3, check_fn)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_network() -> Result<()> {
+        let nodes = 100_000;
+        let name = |i: usize| match i {
+            0 => "AAA".to_string(),
+            _ if i == nodes - 1 => "ZZZ".to_string(),
+            _ => format!("N{i}"),
+        };
+        let mut input = String::from("LR\n\n");
+        for i in 0..nodes {
+            let next = name((i + 1) % nodes);
+            input.push_str(&format!("{} = ({next}, {next})\n", name(i)));
+        }
+
+        let input = parse_input(&input)?;
+        assert_eq!(nodes, input.network.len());
+        assert_eq!(
+            nodes as u64 - 1,
+            part_one(&input.directions, &input.network)?
+        );
+        assert_eq!("N1", input.network.name(input.network.neighbours(0)[0]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_undefined_nodes() {
+        let error = parse_input("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((3, 8), (error.line, error.column));
+        assert_eq!("Node BBB is not defined", error.message);
+
+        let error = parse_input("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!(
+            "Node AAA is defined twice",
+            error.downcast::<ParseError>().unwrap().message
+        );
+    }
+
+    #[test]
+    fn test_crt() -> Result<()> {
+        assert_eq!(Some((7, 15)), crt((1, 3), (2, 5))?);
+        assert_eq!(Some((10, 12)), crt((4, 6), (2, 4))?);
+        assert_eq!(None, crt((1, 6), (2, 4))?);
+        assert!(crt((0, u64::MAX), (0, u64::MAX - 1)).is_err());
+
+        Ok(())
+    }
+}
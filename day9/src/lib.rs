@@ -0,0 +1,86 @@
+use anyhow::Result;
+use aoc_core::{finish, Solution};
+use nom::{
+    character::complete::{self, line_ending, space1},
+    multi::separated_list1,
+    IResult,
+};
+
+#[cfg(has_input)]
+pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+#[cfg(not(has_input))]
+pub const INPUT: Option<&str> = None;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<i32>>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_two(input))
+    }
+}
+
+/// The sum of the extrapolated next values.
+pub fn part_one(input: &[Vec<i32>]) -> i32 {
+    input.iter().map(|line| solve_line(line)).sum()
+}
+
+/// The sum of the extrapolated previous values.
+pub fn part_two(input: &[Vec<i32>]) -> i32 {
+    input
+        .iter()
+        .map(|line| {
+            let reversed = line.iter().rev().copied().collect::<Vec<i32>>();
+            solve_line(&reversed)
+        })
+        .sum()
+}
+
+/// Extrapolates the value following `input` from its repeated differences.
+pub fn solve_line(input: &[i32]) -> i32 {
+    let solution = std::iter::successors(Some(input.to_vec()), |prev_line| {
+        let next_line = prev_line
+            .windows(2)
+            .map(|x| x[1] - x[0])
+            .collect::<Vec<i32>>();
+        if next_line.iter().all(|x| x == &0) {
+            None
+        } else {
+            Some(next_line)
+        }
+    })
+    .collect::<Vec<Vec<i32>>>();
+
+    solution
+        .iter()
+        .rev()
+        .fold(0, |acc, x| x.last().unwrap() + acc)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Vec<i32>>> {
+    separated_list1(line_ending, parse_line)(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(space1, complete::i32)(input)
+}
+
+/// Parses one history of values per line.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>> {
+    let input = finish(input, parse(input))?;
+
+    Ok(input)
+}
@@ -0,0 +1,402 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A position in a [`Grid`], with `x` the column and `y` the row, counting from the top left.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Coord {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Coord { x, y }
+    }
+
+    /// The coordinate `dx` columns and `dy` rows away, or `None` when that is left of or above
+    /// the origin.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Coord> {
+        Some(Coord {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    pub fn manhattan_distance(self, other: Coord) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which all need to have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(GridError::Ragged {
+                    line: y + 1,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|i| &mut self.cells[i])
+    }
+
+    /// All cells with their coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.coord_of(i), cell))
+    }
+
+    /// The coordinate of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.coord_of(i))
+    }
+
+    /// The coordinates directly above, right of, below and left of `coord` that are in the grid.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbours(coord, &NEIGHBOURS_4)
+    }
+
+    /// The coordinates around `coord`, diagonals included, that are in the grid.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbours(coord, &NEIGHBOURS_8)
+    }
+
+    /// The cells of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// When `y` is not a row of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is out of bounds");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// When `x` is not a column of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        coord: Coord,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Coord> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |(dx, dy)| coord.offset(*dx, *dy))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then_some(coord.y * self.width + coord.x)
+    }
+
+    fn coord_of(&self, index: usize) -> Coord {
+        Coord::new(index % self.width, index / self.width)
+    }
+
+    fn rebuild(&self, width: usize, height: usize, cell: impl Fn(Coord) -> Coord) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coord::new(x, y)))
+            .map(|coord| self[cell(coord)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self {
+        self.rebuild(self.height, self.width, |c| Coord::new(c.y, c.x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |c| {
+            Coord::new(c.y, height - 1 - c.x)
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |c| {
+            Coord::new(width - 1 - c.y, c.x)
+        })
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{coord:?} is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{coord:?} is out of bounds"))
+    }
+}
+
+/// Parses one row per line, converting every character into a cell.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        T::try_from(c).map_err(|_| GridError::InvalidCell {
+                            line: y + 1,
+                            column: x + 1,
+                            value: c,
+                        })
+                    })
+                    .collect::<Result<Vec<T>, GridError>>()
+            })
+            .collect::<Result<Vec<Vec<T>>, GridError>>()?;
+
+        Self::from_rows(rows)
+    }
+}
+
+/// Writes one row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Why a grid could not be built; lines and columns count from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    InvalidCell {
+        line: usize,
+        column: usize,
+        value: char,
+    },
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::InvalidCell {
+                line,
+                column,
+                value,
+            } => write!(f, "Invalid cell {value:?} at line {line}, column {column}"),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {line} has {found} cells where {expected} were expected"
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = grid();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(Some(&'f'), grid.get(Coord::new(2, 1)));
+        assert_eq!(None, grid.get(Coord::new(3, 1)));
+        assert_eq!("abc\ndef", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            }),
+            "abc\nde".parse::<Grid<char>>()
+        );
+        assert_eq!(
+            Err(GridError::InvalidCell {
+                line: 2,
+                column: 2,
+                value: 'x'
+            }),
+            "12\n3x".parse::<Grid<Digit>>()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(
+            vec![Coord::new(1, 0), Coord::new(0, 1)],
+            grid.neighbours4(Coord::new(0, 0)).collect::<Vec<Coord>>()
+        );
+        assert_eq!(
+            vec![
+                Coord::new(0, 0),
+                Coord::new(1, 0),
+                Coord::new(2, 0),
+                Coord::new(2, 1),
+                Coord::new(0, 1)
+            ],
+            grid.neighbours8(Coord::new(1, 1)).collect::<Vec<Coord>>()
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(
+            vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]],
+            grid.rows().collect::<Vec<&[char]>>()
+        );
+        assert_eq!("be", grid.column(1).collect::<String>());
+        assert_eq!(3, grid.columns().count());
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = grid();
+
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_counterclockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Digit(u32);
+
+    impl TryFrom<char> for Digit {
+        type Error = ();
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            value.to_digit(10).map(Digit).ok_or(())
+        }
+    }
+}